@@ -0,0 +1,82 @@
+use std::io::{Cursor, Read};
+
+use zip::ZipArchive;
+
+use crate::{
+    error::BraillifyError,
+    xml::{XmlElement, XmlNode},
+};
+
+/// EPUB, HWPX처럼 XML 문서를 zip으로 묶은 파일
+pub struct Archive<'a> {
+    zip: ZipArchive<Cursor<&'a [u8]>>,
+    format: &'static str,
+}
+
+impl<'a> Archive<'a> {
+    pub fn new(bytes: &'a [u8], format: &'static str) -> Result<Self, BraillifyError> {
+        let zip = ZipArchive::new(Cursor::new(bytes)).map_err(|e| document_error(format, e))?;
+        Ok(Self { zip, format })
+    }
+
+    pub fn error(&self, error: impl ToString) -> BraillifyError {
+        document_error(self.format, error)
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.zip.index_for_name(path).is_some()
+    }
+
+    pub fn read(&mut self, path: &str) -> Result<String, BraillifyError> {
+        let format = self.format;
+        let mut entry = self
+            .zip
+            .by_name(path)
+            .map_err(|e| document_error(format, format!("{path}: {e}")))?;
+        let mut text = String::new();
+        entry
+            .read_to_string(&mut text)
+            .map_err(|e| document_error(format, format!("{path}: {e}")))?;
+        Ok(text)
+    }
+}
+
+pub fn document_error(format: &str, error: impl ToString) -> BraillifyError {
+    BraillifyError::DocumentError {
+        format: format.to_string(),
+        error: error.to_string(),
+    }
+}
+
+fn descendants<'a>(nodes: &'a [XmlNode], name: &str, found: &mut Vec<&'a XmlElement>) {
+    for node in nodes {
+        if let XmlNode::Element(element) = node {
+            if element.local_name() == name {
+                found.push(element);
+            }
+            descendants(&element.children, name, found);
+        }
+    }
+}
+
+/// 이름이 `name`인 요소를 문서 순서대로 모두 찾는다.
+pub fn find_all<'a>(nodes: &'a [XmlNode], name: &str) -> Vec<&'a XmlElement> {
+    let mut found = vec![];
+    descendants(nodes, name, &mut found);
+    found
+}
+
+/// 테스트에 쓸 zip 파일을 만든다.
+#[cfg(test)]
+pub fn build_zip(files: &[(&str, &str)]) -> Vec<u8> {
+    use std::io::Write;
+
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    let mut zip = ZipWriter::new(Cursor::new(vec![]));
+    for (name, content) in files {
+        zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
@@ -0,0 +1,440 @@
+use crate::{
+    EncodingConfig, computer,
+    error::BraillifyError,
+    line::{Indent, break_lines, break_lines_indented},
+    rich::{Run, encode_rich_with_breaks},
+    table::{Table, format_table},
+    unicode::decode_unicode,
+};
+
+/// 차례에서 제목과 쪽 번호를 잇는 안내점
+const GUIDE_DOT: u8 = decode_unicode('⠐');
+/// 묵자 쪽이 바뀌는 곳을 알리는 줄
+const PRINT_PAGE_LINE: u8 = decode_unicode('⠤');
+
+/// 책을 이루는 글의 한 덩어리. 글은 서식이 있는 런으로 받는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// 제목. 1단계는 가운데에, 2단계 이하는 단계마다 두 칸씩 더 들여 쓴다.
+    Heading { level: u8, runs: Vec<Run> },
+    /// 문단. 첫 줄을 두 칸 들여 쓴다.
+    Paragraph(Vec<Run>),
+    /// 목록의 한 항목. 묵자의 번호를 `runs`에 함께 쓴다.
+    ListItem { depth: usize, runs: Vec<Run> },
+    /// 인용문. 문단보다 두 칸 더 들여 쓴다.
+    Quote(Vec<Run>),
+    /// 여러 줄의 코드. 컴퓨터 점자로 적는다.
+    Code(String),
+    /// 묵자 원본의 새 쪽이 시작되는 곳
+    PrintPage(String),
+    /// 표. 앞뒤에 빈 줄을 둔다.
+    Table(Table),
+}
+
+impl Block {
+    pub fn heading(level: u8, text: impl Into<String>) -> Self {
+        Self::Heading {
+            level,
+            runs: vec![Run::new(text)],
+        }
+    }
+
+    pub fn paragraph(text: impl Into<String>) -> Self {
+        Self::Paragraph(vec![Run::new(text)])
+    }
+
+    pub fn list_item(depth: usize, text: impl Into<String>) -> Self {
+        Self::ListItem {
+            depth,
+            runs: vec![Run::new(text)],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BookConfig {
+    /// 한 줄의 칸 수
+    pub line_width: usize,
+    /// 한 쪽의 줄 수
+    pub page_lines: usize,
+    /// 한 권에 넣는 쪽 수. 0이면 권을 나누지 않는다.
+    pub volume_pages: usize,
+    /// 쪽마다 마지막 줄 오른쪽 끝에 점자 쪽 번호를 적는다.
+    pub page_numbers: bool,
+    /// 첫째 권 앞에 차례를 넣는다.
+    pub table_of_contents: bool,
+    /// 차례에 넣을 제목의 단계
+    pub contents_levels: u8,
+    pub encoding: EncodingConfig,
+}
+
+impl Default for BookConfig {
+    fn default() -> Self {
+        Self {
+            line_width: 32,
+            page_lines: 25,
+            volume_pages: 0,
+            page_numbers: true,
+            table_of_contents: true,
+            contents_levels: 2,
+            encoding: EncodingConfig::default(),
+        }
+    }
+}
+
+/// 점자 한 쪽. 차례 쪽에는 쪽 번호가 없다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Page {
+    pub number: Option<usize>,
+    pub lines: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Volume {
+    pub pages: Vec<Page>,
+}
+
+/// 차례에 넣을 제목
+struct Entry {
+    level: u8,
+    cells: Vec<u8>,
+    page: usize,
+}
+
+/// 줄을 쪽에 채워 넣는다.
+struct Paginator<'a> {
+    config: &'a BookConfig,
+    pages: Vec<Page>,
+    lines: Vec<Vec<u8>>,
+}
+
+impl<'a> Paginator<'a> {
+    fn new(config: &'a BookConfig) -> Self {
+        Self {
+            config,
+            pages: vec![],
+            lines: vec![],
+        }
+    }
+
+    /// 쪽 번호 줄을 빼고 본문에 쓸 수 있는 줄 수
+    fn body_lines(&self) -> usize {
+        let reserved = usize::from(self.config.page_numbers);
+        self.config.page_lines.saturating_sub(reserved).max(1)
+    }
+
+    fn remaining(&self) -> usize {
+        self.body_lines() - self.lines.len()
+    }
+
+    /// 지금 채우고 있는 쪽의 번호
+    fn page_number(&self) -> usize {
+        self.pages.len() + 1
+    }
+
+    fn new_page(&mut self) {
+        let mut lines = std::mem::take(&mut self.lines);
+        let number = self.page_number();
+        if self.config.page_numbers {
+            lines.resize(self.body_lines(), vec![]);
+            lines.push(right_align(
+                &page_number_cells(number),
+                self.config.line_width,
+            ));
+        }
+        self.pages.push(Page {
+            number: Some(number),
+            lines,
+        });
+    }
+
+    fn push(&mut self, line: Vec<u8>) {
+        if self.remaining() == 0 {
+            self.new_page();
+        }
+        self.lines.push(line);
+    }
+
+    /// 쪽 첫머리가 아니면 빈 줄을 둔다.
+    fn push_blank(&mut self) {
+        if !self.lines.is_empty() && self.remaining() > 0 {
+            self.lines.push(vec![]);
+        }
+    }
+
+    /// `count`줄이 이 쪽에 함께 들어가지 않으면 다음 쪽으로 넘긴다.
+    fn keep_together(&mut self, count: usize) {
+        if !self.lines.is_empty() && self.remaining() < count.min(self.body_lines()) {
+            self.new_page();
+        }
+    }
+
+    fn finish(mut self) -> Vec<Page> {
+        if !self.lines.is_empty() {
+            self.new_page();
+        }
+        self.pages
+    }
+}
+
+fn page_number_cells(number: usize) -> Vec<u8> {
+    // 숫자만 적으므로 실패하지 않는다.
+    crate::encode(&number.to_string()).unwrap_or_default()
+}
+
+fn right_align(cells: &[u8], width: usize) -> Vec<u8> {
+    let mut line = vec![0; width.saturating_sub(cells.len())];
+    line.extend(cells);
+    line
+}
+
+fn center(line: Vec<u8>, width: usize) -> Vec<u8> {
+    let mut centered = vec![0; width.saturating_sub(line.len()) / 2];
+    centered.extend(line);
+    centered
+}
+
+/// 2단계 제목은 네 칸, 3단계 제목은 여섯 칸 들여 쓴다.
+fn heading_indent(level: u8) -> usize {
+    usize::from(level) * 2
+}
+
+/// 차례의 한 항목. 제목 끝과 쪽 번호 사이를 한 칸씩 띄우고 안내점으로 잇는다.
+fn contents_entry(entry: &Entry, width: usize) -> Vec<Vec<u8>> {
+    let number = page_number_cells(entry.page);
+    let indent = usize::from(entry.level.saturating_sub(1)) * 2;
+    // 마지막 줄에 안내점이 두 칸 이상 들어가도록 남겨 둔다.
+    let text_width = width.saturating_sub(number.len() + 4);
+    let mut lines = break_lines_indented(
+        &entry.cells.clone().into(),
+        text_width,
+        Indent {
+            first: indent,
+            runover: indent + 2,
+        },
+    );
+    if let Some(last) = lines.last_mut() {
+        let dots = width.saturating_sub(last.len() + number.len() + 2);
+        last.push(0);
+        last.extend(std::iter::repeat_n(GUIDE_DOT, dots));
+        last.push(0);
+        last.extend(number);
+    }
+    lines
+}
+
+fn contents_pages(entries: &[Entry], config: &BookConfig) -> Result<Vec<Page>, BraillifyError> {
+    let width = config.line_width;
+    let title = crate::encode_with_config("차례", config.encoding.clone())?;
+    let mut lines = vec![center(title, width), vec![]];
+    for entry in entries {
+        lines.extend(contents_entry(entry, width));
+    }
+    let page_lines = config.page_lines.max(1);
+    Ok(lines
+        .chunks(page_lines)
+        .map(|lines| Page {
+            number: None,
+            lines: lines.to_vec(),
+        })
+        .collect())
+}
+
+/// 구조가 있는 문서를 점자 책의 쪽과 권으로 나누어 배치한다.
+/// 본문 쪽에는 1부터 점자 쪽 번호를 붙이고, 차례는 첫째 권 맨 앞에 둔다.
+pub fn format_book(blocks: &[Block], config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    let width = config.line_width.max(1);
+    let mut paginator = Paginator::new(config);
+    let mut entries = vec![];
+    for block in blocks {
+        match block {
+            Block::Heading { level, runs } => {
+                let encoded = encode_rich_with_breaks(runs, &config.encoding)?;
+                let lines = if *level <= 1 {
+                    break_lines(&encoded, width)
+                        .into_iter()
+                        .map(|line| center(line, width))
+                        .collect()
+                } else {
+                    let indent = heading_indent(*level);
+                    break_lines_indented(
+                        &encoded,
+                        width,
+                        Indent {
+                            first: indent,
+                            runover: indent + 2,
+                        },
+                    )
+                };
+                paginator.push_blank();
+                // 제목이 쪽 끝에 홀로 남지 않도록 다음 줄과 함께 넘긴다.
+                paginator.keep_together(lines.len() + 1);
+                if *level <= config.contents_levels {
+                    entries.push(Entry {
+                        level: *level,
+                        cells: encoded.cells,
+                        page: paginator.page_number(),
+                    });
+                }
+                for line in lines {
+                    paginator.push(line);
+                }
+            }
+            Block::Paragraph(runs) | Block::Quote(runs) => {
+                let encoded = encode_rich_with_breaks(runs, &config.encoding)?;
+                let indent = if matches!(block, Block::Quote(_)) {
+                    Indent {
+                        first: 4,
+                        runover: 2,
+                    }
+                } else {
+                    Indent {
+                        first: 2,
+                        runover: 0,
+                    }
+                };
+                for line in break_lines_indented(&encoded, width, indent) {
+                    paginator.push(line);
+                }
+            }
+            Block::ListItem { depth, runs } => {
+                let encoded = encode_rich_with_breaks(runs, &config.encoding)?;
+                let indent = depth * 2;
+                for line in break_lines_indented(
+                    &encoded,
+                    width,
+                    Indent {
+                        first: indent,
+                        runover: indent + 2,
+                    },
+                ) {
+                    paginator.push(line);
+                }
+            }
+            Block::Code(code) => {
+                for line in computer::encode_computer_lines(code)? {
+                    for chunk in line.chunks(width) {
+                        paginator.push(chunk.to_vec());
+                    }
+                }
+            }
+            Block::Table(table) => {
+                paginator.push_blank();
+                for line in format_table(table, width, &config.encoding)? {
+                    paginator.push(line);
+                }
+                paginator.push_blank();
+            }
+            Block::PrintPage(page) => {
+                let number = crate::encode_with_config(page, config.encoding.clone())?;
+                let mut line = vec![PRINT_PAGE_LINE; width.saturating_sub(number.len())];
+                line.extend(number);
+                paginator.push(line);
+            }
+        }
+    }
+    let pages = paginator.finish();
+
+    let mut volumes = if config.volume_pages > 0 {
+        pages
+            .chunks(config.volume_pages)
+            .map(|pages| Volume {
+                pages: pages.to_vec(),
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![Volume { pages }]
+    };
+    if config.table_of_contents && !entries.is_empty() {
+        let contents = contents_pages(&entries, config)?;
+        if let Some(first) = volumes.first_mut() {
+            first.pages.splice(0..0, contents);
+        }
+    }
+    Ok(volumes)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(lines: &[Vec<u8>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|c| encode_unicode(*c)).collect())
+            .collect()
+    }
+
+    #[test]
+    pub fn test_format_book() {
+        let config = BookConfig {
+            line_width: 12,
+            page_lines: 5,
+            ..Default::default()
+        };
+        let volumes = format_book(
+            &[
+                Block::heading(1, "제목"),
+                Block::paragraph("서울은 대한민국의 수도이다."),
+                Block::PrintPage("2".to_string()),
+                Block::heading(2, "소제목"),
+                Block::list_item(0, "1. 사과"),
+            ],
+            &config,
+        )
+        .unwrap();
+        assert_eq!(volumes.len(), 1);
+        let pages = &volumes[0].pages;
+        assert_eq!(
+            pages.iter().map(|page| page.number).collect::<Vec<_>>(),
+            vec![None, Some(1), Some(2)]
+        );
+        assert_eq!(
+            to_unicode(&pages[0].lines),
+            vec!["⠀⠀⠀⠀⠰⠣⠐⠌", "", "⠨⠝⠑⠭⠀⠐⠐⠐⠐⠀⠼⠁", "⠀⠀⠠⠥⠨⠝", "⠀⠀⠀⠀⠑⠭⠀⠐⠐⠀⠼⠃"]
+        );
+        assert_eq!(
+            to_unicode(&pages[1].lines),
+            vec![
+                "⠀⠀⠀⠀⠨⠝⠑⠭",
+                "⠀⠀⠠⠎⠯⠵",
+                "⠊⠗⠚⠒⠑⠟⠈⠍⠁⠺",
+                "⠠⠍⠊⠥⠕⠊⠲",
+                "⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠼⠁"
+            ]
+        );
+        assert_eq!(
+            to_unicode(&pages[2].lines),
+            vec!["⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠼⠃", "", "⠀⠀⠀⠀⠠⠥⠨⠝⠑⠭", "⠼⠁⠲⠀⠇⠈⠧", "⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠼⠃"]
+        );
+    }
+
+    #[test]
+    pub fn test_format_book_volumes() {
+        let config = BookConfig {
+            line_width: 10,
+            page_lines: 3,
+            volume_pages: 2,
+            table_of_contents: false,
+            ..Default::default()
+        };
+        let blocks = (0..5).map(|_| Block::paragraph("사과")).collect::<Vec<_>>();
+        let volumes = format_book(&blocks, &config).unwrap();
+        assert_eq!(
+            volumes
+                .iter()
+                .map(|volume| volume.pages.len())
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(volumes[1].pages[0].number, Some(3));
+        assert!(
+            volumes
+                .iter()
+                .flat_map(|volume| &volume.pages)
+                .all(|page| page.lines.len() == 3)
+        );
+    }
+}
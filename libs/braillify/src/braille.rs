@@ -0,0 +1,131 @@
+use crate::error::BraillifyError;
+
+const MARKUP_START: &str = "[[BR:";
+const MARKUP_END: &str = "]]";
+
+/// 1~8점을 모두 찍은 칸(⣿, U+28FF). 점형 값 255는 줄 바꿈을 나타내므로 적을 수 없다.
+const FULL_CELL: char = '\u{28FF}';
+
+/// 점자 패턴 문자(U+2800~U+28FF)는 그 점형을 그대로 적는다.
+pub fn is_braille_char(c: char) -> bool {
+    ('\u{2800}'..='\u{28FF}').contains(&c)
+}
+
+/// 점자 패턴 문자를 점형 값으로 바꾼다.
+/// ⣿은 줄 바꿈과 값이 겹치므로 `InvalidCharacter` 오류를 낸다.
+pub fn encode_braille_char(c: char) -> Result<u8, BraillifyError> {
+    if c == FULL_CELL {
+        return Err(BraillifyError::InvalidCharacter {
+            character: c,
+            position: None,
+            context: "Braille cell with dots 1-8 is reserved for line breaks".to_string(),
+        });
+    }
+    if !is_braille_char(c) {
+        return Err(BraillifyError::InvalidCharacter {
+            character: c,
+            position: None,
+            context: "Not a literal braille cell".to_string(),
+        });
+    }
+    Ok((c as u32 - 0x2800) as u8)
+}
+
+/// 점 번호(1~8)로 적은 칸 하나. `0`은 빈칸이다. (`145`, `1-4-5`)
+fn encode_dots(token: &str) -> Result<u8, BraillifyError> {
+    let mut cell = 0u8;
+    for c in token.chars().filter(|c| *c != '-') {
+        match c.to_digit(10) {
+            Some(0) if token == "0" => {}
+            Some(dot @ 1..=8) => cell |= 1 << (dot - 1),
+            _ => {
+                return Err(BraillifyError::InvalidCharacter {
+                    character: c,
+                    position: None,
+                    context: format!("Invalid braille dot number in '{token}'"),
+                });
+            }
+        }
+    }
+    if cell == 255 {
+        return encode_braille_char(FULL_CELL);
+    }
+    Ok(cell)
+}
+
+/// `[[BR: …]]`로 감싼 점자를 찾아 (앞부분, 내용, 뒷부분)으로 나눈다.
+pub fn split_escape(word: &str) -> Option<(&str, &str, &str)> {
+    let start = word.find(MARKUP_START)?;
+    let content = start + MARKUP_START.len();
+    let end = content + word[content..].find(MARKUP_END)?;
+    Some((
+        &word[..start],
+        &word[content..end],
+        &word[end + MARKUP_END.len()..],
+    ))
+}
+
+/// `[[BR: …]]` 안의 점자를 적는다. 점자 패턴 문자를 그대로 쓰거나,
+/// 칸마다 점 번호를 공백으로 나누어 쓴다. (`[[BR: ⠿⠇]]`, `[[BR: 123456 0 123]]`)
+pub fn encode_escape(content: &str) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = vec![];
+    for token in content.split_whitespace() {
+        if token.chars().all(|c| c.is_ascii_digit() || c == '-') {
+            result.push(encode_dots(token)?);
+        } else {
+            for c in token.chars() {
+                result.push(encode_braille_char(c)?);
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_encode_braille_char() {
+        assert!(is_braille_char('⠀'));
+        assert!(is_braille_char('⡇'));
+        assert!(is_braille_char('⣿'));
+        assert!(!is_braille_char('a'));
+        assert_eq!(encode_braille_char('⠿').unwrap(), 63);
+        assert_eq!(encode_braille_char('⡀').unwrap(), 64);
+        assert_eq!(encode_braille_char('⣾').unwrap(), 254);
+        assert_eq!(
+            encode_braille_char('⣿'),
+            Err(BraillifyError::InvalidCharacter {
+                character: '⣿',
+                position: None,
+                context: "Braille cell with dots 1-8 is reserved for line breaks".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_split_escape() {
+        assert_eq!(
+            split_escape("그림[[BR: ⠿⠇]]은"),
+            Some(("그림", " ⠿⠇", "은"))
+        );
+        assert_eq!(split_escape("[[BR: ⠿"), None);
+        assert_eq!(split_escape("그림"), None);
+    }
+
+    #[test]
+    pub fn test_encode_escape() {
+        assert_eq!(encode_escape(" ⠿⠇ ").unwrap(), vec![63, 7]);
+        assert_eq!(encode_escape("123456 0 1-2-3").unwrap(), vec![63, 0, 7]);
+        assert_eq!(encode_escape("78").unwrap(), vec![192]);
+        assert!(encode_escape("129").is_err());
+        assert_eq!(
+            encode_escape("12345678"),
+            encode_escape("⣿"),
+            "1~8점 칸은 줄 바꿈과 겹쳐 점 번호로도 적을 수 없다"
+        );
+        assert!(encode_escape("12345678").is_err());
+        assert!(encode_escape("ab").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{error::BraillifyError, math_symbol_shortcut::is_math_symbol_char, symbol_shortcut::is_symbol_char, fraction::is_unicode_fraction};
+use crate::{braille::is_braille_char, english::is_english_letter, error::BraillifyError, japanese::is_japanese_char, math_symbol_shortcut::is_math_symbol_char, symbol_shortcut::is_symbol_char, fraction::is_unicode_fraction, unit::is_unit_sign, enumerator::is_enumerator_char};
 
 /// Character in Korean
 #[derive(Debug)]
@@ -59,12 +59,16 @@ pub enum CharType {
     Symbol(char),
     MathSymbol(char),
     Fraction(char), 
+    Unit(char),
+    Enumerator(char),
+    Japanese(char),
+    Braille(char),
     Space(char),
 }
 
 impl CharType {
     pub fn new(c: char) -> Result<Self, BraillifyError> {
-        if c.is_ascii_alphabetic() {
+        if is_english_letter(c) {
             return Ok(Self::English(c));
         }
         if c.is_ascii_digit() {
@@ -79,6 +83,12 @@ impl CharType {
         if is_unicode_fraction(c) { 
             return Ok(Self::Fraction(c));
         }
+        if is_unit_sign(c) {
+            return Ok(Self::Unit(c));
+        }
+        if is_enumerator_char(c) {
+            return Ok(Self::Enumerator(c));
+        }
         let code = c as u32;
         if (0x3131..=0x3163).contains(&code) {
             return Ok(Self::KoreanPart(c));
@@ -89,6 +99,12 @@ impl CharType {
         if (0xAC00..=0xD7A3).contains(&code) {
             return Ok(Self::Korean(KoreanChar::new(c)?));
         }
+        if is_japanese_char(c) {
+            return Ok(Self::Japanese(c));
+        }
+        if is_braille_char(c) {
+            return Ok(Self::Braille(c));
+        }
         if c.is_whitespace() {
             return Ok(Self::Space(c));
         }
@@ -119,6 +135,19 @@ mod test {
             CharType::new('½').unwrap(), 
             CharType::Fraction('½')
         ));
+        assert!(matches!(CharType::new('%').unwrap(), CharType::Unit('%')));
+        assert!(matches!(
+            CharType::new('①').unwrap(),
+            CharType::Enumerator('①')
+        ));
+        assert!(matches!(
+            CharType::new('カ').unwrap(),
+            CharType::Japanese('カ')
+        ));
+        assert!(matches!(
+            CharType::new('⠿').unwrap(),
+            CharType::Braille('⠿')
+        ));
     }
 
     proptest! {
@@ -138,7 +167,7 @@ mod test {
                     assert!(0x3131 <= code && code <= 0x3163);
                 }
                 CharType::English(ch) => {
-                    assert!(ch.is_ascii_alphabetic());
+                    assert!(is_english_letter(ch));
                 }
                 CharType::Number(ch) => {
                     assert!(ch.is_ascii_digit());
@@ -155,6 +184,18 @@ mod test {
                 CharType::Fraction(ch) => {
                     assert!(is_unicode_fraction(ch));
                 }
+                CharType::Unit(ch) => {
+                    assert!(is_unit_sign(ch));
+                }
+                CharType::Enumerator(ch) => {
+                    assert!(is_enumerator_char(ch));
+                }
+                CharType::Japanese(ch) => {
+                    assert!(is_japanese_char(ch));
+                }
+                CharType::Braille(ch) => {
+                    assert!(is_braille_char(ch));
+                }
             }
         }
     }
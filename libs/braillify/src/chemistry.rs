@@ -0,0 +1,345 @@
+use phf::phf_set;
+
+use crate::{
+    english::encode_english_letter, error::BraillifyError, number::encode_number,
+    symbol_shortcut::encode_char_symbol_shortcut, unicode::decode_unicode,
+};
+
+/// 원소 기호
+static ELEMENTS: phf::Set<&'static str> = phf_set! {
+    "H", "He", "Li", "Be", "B", "C", "N", "O", "F", "Ne", "Na", "Mg", "Al", "Si", "P", "S",
+    "Cl", "Ar", "K", "Ca", "Sc", "Ti", "V", "Cr", "Mn", "Fe", "Co", "Ni", "Cu", "Zn", "Ga",
+    "Ge", "As", "Se", "Br", "Kr", "Rb", "Sr", "Y", "Zr", "Nb", "Mo", "Tc", "Ru", "Rh", "Pd",
+    "Ag", "Cd", "In", "Sn", "Sb", "Te", "I", "Xe", "Cs", "Ba", "La", "Ce", "Pr", "Nd", "Pm",
+    "Sm", "Eu", "Gd", "Tb", "Dy", "Ho", "Er", "Tm", "Yb", "Lu", "Hf", "Ta", "W", "Re", "Os",
+    "Ir", "Pt", "Au", "Hg", "Tl", "Pb", "Bi", "Po", "At", "Rn", "Fr", "Ra", "Ac", "Th", "Pa",
+    "U", "Np", "Pu", "Am", "Cm", "Bk", "Cf", "Es", "Fm", "Md", "No", "Lr", "Rf", "Db", "Sg",
+    "Bh", "Hs", "Mt", "Ds", "Rg", "Cn", "Nh", "Fl", "Mc", "Lv", "Ts", "Og",
+};
+
+/// 화학 반응식의 화살표. 이 화살표가 있는 글은 화학 문맥으로 본다.
+pub const REACTION_ARROWS: [char; 4] = ['→', '⇌', '⟶', '⇄'];
+
+/// 화학식을 이루는 단위
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// 계수 (2H₂O의 2, CuSO₄·5H₂O의 5)
+    Coefficient(String),
+    Element(String),
+    /// 아래첨자 숫자 (원자 수)
+    Subscript(String),
+    /// 윗첨자 숫자와 전하 (질량수 ¹⁴, 이온 전하 ²⁻)
+    Superscript(String),
+    Open(char),
+    Close(char),
+    /// 수화물의 가운뎃점
+    Dot,
+}
+
+fn subscript_digit(c: char) -> Option<char> {
+    match c {
+        '₀'..='₉' => char::from_digit(c as u32 - '₀' as u32, 10),
+        _ => None,
+    }
+}
+
+fn superscript_char(c: char) -> Option<char> {
+    match c {
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴'..='⁹' => char::from_digit(c as u32 - '⁰' as u32, 10),
+        '⁺' => Some('+'),
+        '⁻' => Some('-'),
+        _ => None,
+    }
+}
+
+/// 단어 앞부분에서 화학식이 될 수 있는 만큼 읽는다. 읽은 글자 수와 단위를 돌려준다.
+fn tokenize(chars: &[char]) -> (usize, Vec<Token>) {
+    let mut tokens = vec![];
+    let mut depth = 0;
+    let mut i = 0;
+    let take_while = |i: &mut usize, f: &dyn Fn(char) -> Option<char>| {
+        let mut text = String::new();
+        while let Some(c) = chars.get(*i).and_then(|c| f(*c)) {
+            text.push(c);
+            *i += 1;
+        }
+        text
+    };
+    while i < chars.len() {
+        let c = chars[i];
+        let after_operand = matches!(
+            tokens.last(),
+            Some(Token::Element(_) | Token::Close(_) | Token::Subscript(_))
+        );
+        if c.is_ascii_digit() {
+            let digits = take_while(&mut i, &|c| c.is_ascii_digit().then_some(c));
+            tokens.push(if after_operand {
+                // 본문에서 흔히 CO2처럼 아래첨자를 보통 숫자로 적는다.
+                Token::Subscript(digits)
+            } else {
+                Token::Coefficient(digits)
+            });
+        } else if subscript_digit(c).is_some() && after_operand {
+            tokens.push(Token::Subscript(take_while(&mut i, &subscript_digit)));
+        } else if superscript_char(c).is_some() {
+            tokens.push(Token::Superscript(take_while(&mut i, &superscript_char)));
+        } else if c.is_ascii_uppercase() {
+            let two = chars
+                .get(i + 1)
+                .filter(|c| c.is_ascii_lowercase())
+                .map(|next| format!("{c}{next}"));
+            if let Some(two) = two.filter(|two| ELEMENTS.contains(two.as_str())) {
+                tokens.push(Token::Element(two));
+                i += 2;
+            } else if ELEMENTS.contains(c.to_string().as_str()) {
+                tokens.push(Token::Element(c.to_string()));
+                i += 1;
+            } else {
+                break;
+            }
+        } else if matches!(c, '(' | '[') {
+            tokens.push(Token::Open(c));
+            depth += 1;
+            i += 1;
+        } else if matches!(c, ')' | ']') && depth > 0 {
+            tokens.push(Token::Close(c));
+            depth -= 1;
+            i += 1;
+        } else if c == '·' && after_operand {
+            tokens.push(Token::Dot);
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    // 닫히지 않은 괄호나 가운뎃점으로 끝나면 그 앞까지만 화학식으로 본다.
+    while depth > 0 || matches!(tokens.last(), Some(Token::Dot | Token::Open(_))) {
+        match tokens.pop() {
+            Some(Token::Open(_)) => depth -= 1,
+            Some(Token::Close(_)) => depth += 1,
+            None => break,
+            _ => {}
+        }
+    }
+    let len = tokens
+        .iter()
+        .map(|token| match token {
+            Token::Coefficient(text) | Token::Subscript(text) | Token::Superscript(text) => {
+                text.chars().count()
+            }
+            Token::Element(symbol) => symbol.len(),
+            Token::Open(_) | Token::Close(_) | Token::Dot => 1,
+        })
+        .sum();
+    (len, tokens)
+}
+
+/// 원소 기호처럼 보이는 로마자 약어(PS5, HP2, NaN)와 구별하기 위해
+/// 전하, 질량수, 원소 기호 둘 이상과 아래첨자가 있는 것만 화학식으로 본다.
+/// 화학 문맥(`chemistry`)에서는 첨자가 있거나, 원소 기호가 둘 이상이면서
+/// 소문자나 숫자가 섞인 것(NaCl, CO2)도 화학식으로 본다.
+fn is_formula(tokens: &[Token], has_subscript: bool, has_script: bool, chemistry: bool) -> bool {
+    let elements = tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Element(symbol) => Some(symbol),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if elements.is_empty() {
+        return false;
+    }
+    let charge = tokens
+        .iter()
+        .any(|token| matches!(token, Token::Superscript(text) if text.contains(['+', '-'])));
+    let mass_number = tokens
+        .windows(2)
+        .any(|pair| matches!(pair, [Token::Superscript(_), Token::Element(_)]));
+    if charge || mass_number || (has_subscript && elements.len() >= 2) {
+        return true;
+    }
+    chemistry
+        && (has_script
+            || (elements.len() >= 2
+                && (elements.iter().any(|symbol| symbol.len() == 2)
+                    || tokens
+                        .iter()
+                        .any(|token| matches!(token, Token::Subscript(_))))))
+}
+
+/// 단어에서 화학식을 찾아 (앞부분, 화학식, 뒷부분)으로 나눈다.
+/// 화학식 앞이나 뒤에 로마자나 숫자가 이어지면 화학식으로 보지 않는다.
+/// `chemistry`는 반응식 안이거나 화학 문서로 지정한 경우이다.
+pub fn split_formula(word: &str, chemistry: bool) -> Option<(&str, &str, &str)> {
+    let chars = word.chars().collect::<Vec<_>>();
+    (0..chars.len()).find_map(|start| {
+        if start > 0
+            && (chars[start - 1].is_ascii_alphanumeric()
+                || superscript_char(chars[start - 1]).is_some()
+                || subscript_digit(chars[start - 1]).is_some())
+        {
+            return None;
+        }
+        let (len, tokens) = tokenize(&chars[start..]);
+        let end = start + len;
+        if len == 0 || chars.get(end).is_some_and(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        let has_subscript = chars[start..end]
+            .iter()
+            .any(|c| subscript_digit(*c).is_some());
+        let has_script = has_subscript
+            || chars[start..end]
+                .iter()
+                .any(|c| superscript_char(*c).is_some());
+        if !is_formula(&tokens, has_subscript, has_script, chemistry) {
+            return None;
+        }
+        let byte = |idx: usize| chars[..idx].iter().map(|c| c.len_utf8()).sum::<usize>();
+        let (start, end) = (byte(start), byte(end));
+        Some((&word[..start], &word[start..end], &word[end..]))
+    })
+}
+
+fn encode_digits(digits: &str, result: &mut Vec<u8>) -> Result<(), BraillifyError> {
+    for digit in digits.chars() {
+        result.push(encode_number(digit)?);
+    }
+    Ok(())
+}
+
+/// 화학식을 적는다.
+/// 원소 기호의 대문자마다 대문자 기호표를 적고(제28항의 대문자 단어표를 쓰지 않는다),
+/// 아래첨자는 ⠰, 윗첨자(질량수, 전하)는 ⠘ 뒤에 적는다.
+pub fn encode_formula(formula: &str) -> Result<Vec<u8>, BraillifyError> {
+    let chars = formula.chars().collect::<Vec<_>>();
+    let (len, tokens) = tokenize(&chars);
+    if len != chars.len() {
+        return Err(BraillifyError::InvalidCharacter {
+            character: chars[len],
+            position: Some(len),
+            context: format!("Invalid chemical formula '{formula}'"),
+        });
+    }
+    let mut result = vec![];
+    for token in tokens {
+        match token {
+            Token::Coefficient(digits) => {
+                result.push(decode_unicode('⠼'));
+                encode_digits(&digits, &mut result)?;
+            }
+            Token::Element(symbol) => {
+                result.push(decode_unicode('⠠'));
+                for c in symbol.chars() {
+                    result.extend(encode_english_letter(c.to_ascii_lowercase())?);
+                }
+            }
+            Token::Subscript(digits) => {
+                result.extend([decode_unicode('⠰'), decode_unicode('⠼')]);
+                encode_digits(&digits, &mut result)?;
+            }
+            Token::Superscript(text) => {
+                result.push(decode_unicode('⠘'));
+                let mut is_number = false;
+                for c in text.chars() {
+                    match c {
+                        '+' => result.push(decode_unicode('⠢')),
+                        '-' => result.push(decode_unicode('⠔')),
+                        _ => {
+                            if !is_number {
+                                result.push(decode_unicode('⠼'));
+                            }
+                            result.push(encode_number(c)?);
+                        }
+                    }
+                    is_number = c.is_ascii_digit();
+                }
+            }
+            Token::Open(c) | Token::Close(c) => {
+                result.extend(encode_char_symbol_shortcut(c)?);
+            }
+            Token::Dot => result.extend(encode_char_symbol_shortcut('·')?),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(formula: &str) -> String {
+        encode_formula(formula)
+            .unwrap()
+            .iter()
+            .map(|c| encode_unicode(*c))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_split_formula() {
+        assert_eq!(split_formula("H₂O", false), Some(("", "H₂O", "")));
+        assert_eq!(split_formula("CO₂는", false), Some(("", "CO₂", "는")));
+        assert_eq!(split_formula("‘H₂O’", false), Some(("‘", "H₂O", "’")));
+        assert_eq!(
+            split_formula("Ca(OH)₂와", false),
+            Some(("", "Ca(OH)₂", "와"))
+        );
+        assert_eq!(split_formula("¹⁴C", false), Some(("", "¹⁴C", "")));
+        assert_eq!(split_formula("Na⁺", false), Some(("", "Na⁺", "")));
+        assert_eq!(
+            split_formula("CuSO₄·5H₂O", false),
+            Some(("", "CuSO₄·5H₂O", ""))
+        );
+        assert_eq!(
+            split_formula("물(H₂O)은", false),
+            Some(("물", "(H₂O)", "은"))
+        );
+        assert_eq!(split_formula("OK", false), None);
+        assert_eq!(split_formula("CO", false), None);
+        assert_eq!(split_formula("Hello", false), None);
+        assert_eq!(split_formula("YouTube", false), None);
+        assert_eq!(split_formula("MP3", false), None);
+        assert_eq!(split_formula("x²", false), None);
+        assert_eq!(split_formula("xH₂O", false), None);
+        assert_eq!(split_formula("사과", false), None);
+    }
+
+    #[test]
+    pub fn test_split_formula_context() {
+        // 화학 문맥이 없으면 원소 기호처럼 보이는 약어를 화학식으로 보지 않는다.
+        assert_eq!(split_formula("PS5", false), None);
+        assert_eq!(split_formula("HP2", false), None);
+        assert_eq!(split_formula("NaN", false), None);
+        assert_eq!(split_formula("(NaCl)", false), None);
+        assert_eq!(split_formula("CO2", false), None);
+        assert_eq!(split_formula("H₂", false), None);
+        assert_eq!(split_formula("C²", false), None);
+
+        assert_eq!(split_formula("(NaCl)", true), Some(("", "(NaCl)", "")));
+        assert_eq!(split_formula("CO2", true), Some(("", "CO2", "")));
+        assert_eq!(split_formula("H₂", true), Some(("", "H₂", "")));
+        assert_eq!(split_formula("OK", true), None);
+        assert_eq!(split_formula("CO", true), None);
+    }
+
+    #[test]
+    pub fn test_encode_formula() {
+        assert_eq!(to_unicode("H₂O"), "⠠⠓⠰⠼⠃⠠⠕");
+        assert_eq!(to_unicode("CO₂"), "⠠⠉⠠⠕⠰⠼⠃");
+        assert_eq!(to_unicode("2H₂O"), "⠼⠃⠠⠓⠰⠼⠃⠠⠕");
+        assert_eq!(to_unicode("NaCl"), "⠠⠝⠁⠠⠉⠇");
+        assert_eq!(to_unicode("SO₄²⁻"), "⠠⠎⠠⠕⠰⠼⠙⠘⠼⠃⠔");
+        assert_eq!(to_unicode("Na⁺"), "⠠⠝⠁⠘⠢");
+        assert_eq!(to_unicode("¹⁴C"), "⠘⠼⠁⠙⠠⠉");
+        assert_eq!(to_unicode("Ca(OH)₂"), "⠠⠉⠁⠦⠄⠠⠕⠠⠓⠠⠴⠰⠼⠃");
+        assert_eq!(to_unicode("CuSO₄·5H₂O"), "⠠⠉⠥⠠⠎⠠⠕⠰⠼⠙⠐⠆⠼⠑⠠⠓⠰⠼⠃⠠⠕");
+        assert!(encode_formula("H₂O!").is_err());
+    }
+}
@@ -1,18 +1,108 @@
 use std::io::{self, IsTerminal, Read, Write};
 
 use anyhow::{Result, bail};
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use rustyline::{DefaultEditor, error::ReadlineError};
 
-use crate::encode_to_unicode;
+use crate::{
+    BookConfig, Encoder, Volume, encode_with_warnings,
+    error::{BraillifyError, EncodingWarning},
+    unicode, utils,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "braillify", about = "한국어 점자 변환 CLI", version)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// 입력 문자열. 없으면 REPL 모드로 진입합니다
     input: Option<String>,
 }
 
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// HTML 파일을 점자 책으로 변환합니다
+    Html {
+        file: std::path::PathBuf,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+    /// 워드 문서(.docx)를 점자 책으로 변환합니다
+    #[cfg(feature = "docx")]
+    Docx {
+        file: std::path::PathBuf,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+    /// EPUB 파일을 점자 책으로 변환합니다
+    #[cfg(feature = "epub")]
+    Epub {
+        file: std::path::PathBuf,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+    /// 한글 5.0 문서(.hwp)를 점자 책으로 변환합니다
+    #[cfg(feature = "hwp")]
+    Hwp {
+        file: std::path::PathBuf,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+    /// HWPX(한컴오피스) 문서를 점자 책으로 변환합니다
+    #[cfg(feature = "hwpx")]
+    Hwpx {
+        file: std::path::PathBuf,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+    /// OpenDocument 텍스트 문서(.odt)를 점자 책으로 변환합니다
+    #[cfg(feature = "odt")]
+    Odt {
+        file: std::path::PathBuf,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+    /// 글자가 들어 있는 PDF를 점자 책으로 변환합니다
+    #[cfg(feature = "pdf")]
+    Pdf {
+        file: std::path::PathBuf,
+        /// 쪽마다 되풀이되는 머리글과 바닥글을 지웁니다
+        #[arg(long)]
+        drop_headers: bool,
+        #[command(flatten)]
+        book: BookArgs,
+    },
+}
+
+/// 점자 책의 쪽 배치 설정
+#[derive(Args, Debug)]
+struct BookArgs {
+    /// 한 줄의 칸 수
+    #[arg(long, default_value_t = 32)]
+    width: usize,
+    /// 한 쪽의 줄 수
+    #[arg(long, default_value_t = 25)]
+    lines: usize,
+    /// 한 권에 넣는 쪽 수. 0이면 권을 나누지 않습니다
+    #[arg(long, default_value_t = 0)]
+    volume_pages: usize,
+    /// 유니코드 점자 대신 점자 ASCII(BRF)로 적습니다
+    #[arg(long)]
+    brf: bool,
+}
+
+impl BookArgs {
+    fn config(&self) -> BookConfig {
+        BookConfig {
+            line_width: self.width,
+            page_lines: self.lines,
+            volume_pages: self.volume_pages,
+            ..Default::default()
+        }
+    }
+}
+
 pub fn run_cli(mut args: Vec<String>) -> Result<()> {
     if args.len() == 1 && !std::io::stdin().is_terminal() {
         let mut buffer = vec![];
@@ -21,14 +111,138 @@ pub fn run_cli(mut args: Vec<String>) -> Result<()> {
             args.push(String::from_utf8(buffer)?);
         }
     }
-    match Cli::parse_from(args).input {
-        Some(text) => run_one_shot(&text),
-        None => run_repl(),
+    let cli = Cli::parse_from(args);
+    match cli.command {
+        Some(Command::Html { file, book }) => {
+            let volumes = crate::encode_html(&std::fs::read_to_string(file)?, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        #[cfg(feature = "docx")]
+        Some(Command::Docx { file, book }) => {
+            let volumes = crate::encode_docx(&std::fs::read(file)?, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        #[cfg(feature = "epub")]
+        Some(Command::Epub { file, book }) => {
+            let volumes = crate::encode_epub(&std::fs::read(file)?, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        #[cfg(feature = "hwp")]
+        Some(Command::Hwp { file, book }) => {
+            let volumes = crate::encode_hwp(&std::fs::read(file)?, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        #[cfg(feature = "hwpx")]
+        Some(Command::Hwpx { file, book }) => {
+            let volumes = crate::encode_hwpx(&std::fs::read(file)?, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        #[cfg(feature = "odt")]
+        Some(Command::Odt { file, book }) => {
+            let volumes = crate::encode_odt(&std::fs::read(file)?, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        #[cfg(feature = "pdf")]
+        Some(Command::Pdf {
+            file,
+            drop_headers,
+            book,
+        }) => {
+            let pdf = crate::PdfConfig {
+                drop_headers_footers: drop_headers,
+            };
+            let volumes = crate::encode_pdf(&std::fs::read(file)?, &pdf, &book.config())
+                .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+            write_volumes(&volumes, book.brf)
+        }
+        None => match cli.input {
+            Some(text) => run_one_shot(&text),
+            None => run_repl(),
+        },
+    }
+}
+
+/// 점자 책을 줄마다 유니코드 점자로 적는다. 쪽 사이에는 폼 피드를 넣는다.
+/// BRF는 점자 ASCII로 적고 줄을 CR LF로 끝낸다.
+fn write_volumes(volumes: &[Volume], brf: bool) -> Result<()> {
+    let mut stdout = io::stdout();
+    let pages = volumes.iter().flat_map(|volume| &volume.pages);
+    for (idx, page) in pages.enumerate() {
+        if idx > 0 {
+            write!(stdout, "\u{c}")?;
+        }
+        for line in &page.lines {
+            if brf {
+                write!(stdout, "{}\r\n", to_braille_ascii(line)?)?;
+            } else {
+                writeln!(stdout, "{}", to_unicode(line))?;
+            }
+        }
     }
+    stdout.flush()?;
+    Ok(())
+}
+
+const MARKUP_START: &str = "[[TN:";
+const MARKUP_END: &str = "]]";
+
+/// `[[TN: …]]`로 적은 점역자 주를 찾아 (앞부분, 주, 뒷부분)으로 나눈다.
+fn split_note_markup(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find(MARKUP_START)?;
+    let content = start + MARKUP_START.len();
+    let end = content + text[content..].find(MARKUP_END)?;
+    Some((
+        &text[..start],
+        text[content..end].trim(),
+        &text[end + MARKUP_END.len()..],
+    ))
+}
+
+/// 입력 안의 `[[TN: …]]`는 점역자 주로 적는다.
+fn encode_with_notes(text: &str) -> Result<(Vec<u8>, Vec<EncodingWarning>), BraillifyError> {
+    if split_note_markup(text).is_none() {
+        return encode_with_warnings(text);
+    }
+    let mut encoder = Encoder::new(text.chars().any(utils::is_korean_char));
+    let mut result = vec![];
+    let mut rest = text;
+    while let Some((before, note, after)) = split_note_markup(rest) {
+        encoder.encode(before, &mut result)?;
+        encoder.insert_note(note, &mut result)?;
+        if after.starts_with(' ') && !after.trim().is_empty() {
+            result.push(0);
+        }
+        rest = after;
+    }
+    encoder.encode(rest, &mut result)?;
+    encoder.finish(&mut result)?;
+    Ok((result, encoder.take_warnings()))
+}
+
+fn to_unicode(cells: &[u8]) -> String {
+    cells.iter().map(|c| unicode::encode_unicode(*c)).collect()
+}
+
+fn to_braille_ascii(cells: &[u8]) -> Result<String, BraillifyError> {
+    cells
+        .iter()
+        .map(|c| unicode::encode_braille_ascii(*c))
+        .collect()
 }
 
 fn run_one_shot(text: &str) -> Result<()> {
-    let out = encode_to_unicode(text).map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+    let (cells, warnings) =
+        encode_with_notes(text).map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+    for warning in warnings {
+        eprintln!("경고: {}", warning);
+    }
+    let out = to_unicode(&cells);
     let mut stdout = io::stdout();
     stdout.write_all(out.as_bytes())?;
     stdout.flush()?;
@@ -48,8 +262,8 @@ fn run_repl() -> Result<()> {
         match rl.readline("> ") {
             Ok(line) => {
                 rl.add_history_entry(&line).ok();
-                match encode_to_unicode(&line) {
-                    Ok(out) => writeln!(stdout, "{}", out)?,
+                match encode_with_notes(&line) {
+                    Ok((cells, _)) => writeln!(stdout, "{}", to_unicode(&cells))?,
                     Err(e) => writeln!(stdout, "오류: {}", e)?,
                 }
                 stdout.flush()?;
@@ -82,6 +296,31 @@ mod tests {
         assert_eq!(cli.input, None);
     }
 
+    #[test]
+    fn test_cli_parsing_subcommand() {
+        let cli = Cli::try_parse_from(["braillify", "html", "a.html", "--width", "40"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Html {
+                book: BookArgs {
+                    width: 40,
+                    brf: false,
+                    ..
+                },
+                ..
+            })
+        ));
+        let cli = Cli::try_parse_from(["braillify", "html", "a.html", "--brf"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Html {
+                book: BookArgs { brf: true, .. },
+                ..
+            })
+        ));
+        assert_eq!(cli.input, None);
+    }
+
     // 유닛 테스트들
     #[test]
     fn test_run_one_shot_success() {
@@ -121,6 +360,28 @@ mod tests {
         let _ = result;
     }
 
+    #[test]
+    fn test_split_note_markup() {
+        assert_eq!(
+            split_note_markup("사진 [[TN: 바다 풍경]] 아래"),
+            Some(("사진 ", "바다 풍경", " 아래"))
+        );
+        assert_eq!(
+            split_note_markup("[[TN:그림]]"),
+            Some(("", "그림", ""))
+        );
+        assert_eq!(split_note_markup("[[TN: 닫히지 않음"), None);
+        assert_eq!(split_note_markup("[[그림]]"), None);
+    }
+
+    #[test]
+    fn test_encode_with_notes() {
+        let (cells, _) = encode_with_notes("사진 [[TN: 바다 풍경]] 아래").unwrap();
+        assert_eq!(to_unicode(&cells), "⠇⠨⠟⠀⠸⠦⠘⠊⠀⠙⠍⠶⠈⠻⠴⠇⠀⠣⠐⠗");
+        let (cells, _) = encode_with_notes("그림[[TN: 지도]].").unwrap();
+        assert_eq!(to_unicode(&cells), "⠈⠪⠐⠕⠢⠀⠸⠦⠨⠕⠊⠥⠴⠇⠲");
+    }
+
     #[test]
     fn test_braillify_invalid_input() {
         let result = run_one_shot("§");
@@ -0,0 +1,173 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    error::BraillifyError,
+    unicode::{BRAILLE_ASCII, decode_unicode},
+};
+
+/// 컴퓨터 점자 시작표
+const START: [u8; 2] = [decode_unicode('⠠'), decode_unicode('⠶')];
+/// 컴퓨터 점자 종료표
+const END: [u8; 2] = [decode_unicode('⠶'), decode_unicode('⠄')];
+
+/// 인터넷 주소, 전자 우편 주소, 파일 경로
+static ADDRESS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(?i:https?|ftp)://[!-~]+",
+        r"|(?i:www)\.[A-Za-z0-9\-]+(?:\.[A-Za-z0-9\-]+)+[!-~]*",
+        r"|[A-Za-z0-9._%+\-]+@[A-Za-z0-9\-]+(?:\.[A-Za-z0-9\-]+)+",
+        r"|(?:[A-Za-z]:\\|~/|\.\.?/)[A-Za-z0-9._\-][!-~]*",
+        r"|/[A-Za-z0-9._\-]+(?:/[A-Za-z0-9._\-]*)+",
+    ))
+    .expect("Failed to compile ADDRESS_REGEX")
+});
+
+/// 주소 끝에 붙은 문장 부호는 주소가 아니라 문장에 속한다.
+const TRAILING_PUNCTUATION: [char; 9] = ['.', ',', ';', ':', '!', '?', ')', '\'', '"'];
+
+/// 컴퓨터 점자 대문자표. 로마자 대문자와 ` { | } ~ 앞에 적어 소문자와 @ [ \ ] ^와 구별한다.
+/// 쉼표도 같은 점형이므로 쉼표는 이 표를 두 번 적는다.
+const SHIFT: u8 = decode_unicode('⠠');
+
+/// 아스키 문자를 컴퓨터 점자로 적는다. 서로 다른 글자는 서로 다른 점형이 되도록
+/// 점자 ASCII 표에 없는 글자는 대문자표를 앞세워 적는다.
+fn encode_ascii(c: char) -> Option<Vec<u8>> {
+    let (shift, c) = match c {
+        'A'..='Z' | ',' => (true, c),
+        'a'..='z' => (false, c.to_ascii_uppercase()),
+        '`' | '{' | '|' | '}' | '~' => (true, char::from(c as u8 - 0x20)),
+        _ => (false, c),
+    };
+    let code = BRAILLE_ASCII.find(c)? as u8;
+    Some(if shift { vec![SHIFT, code] } else { vec![code] })
+}
+
+/// 약자와 약어를 쓰지 않고 한 글자씩 컴퓨터 점자로 적는다.
+/// 아스키 문자가 아닌 글자는 일반 점자로 적는다.
+pub fn encode_computer(text: &str) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = vec![];
+    for c in text.chars() {
+        match encode_ascii(c) {
+            Some(code) => result.extend(code),
+            None => result.extend(crate::encode(&c.to_string())?),
+        }
+    }
+    Ok(result)
+}
+
+/// 컴퓨터 점자 시작표와 종료표 사이에 컴퓨터 점자로 적는다.
+pub fn encode_computer_span(text: &str) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = START.to_vec();
+    result.extend(encode_computer(text)?);
+    result.extend(END);
+    Ok(result)
+}
+
+/// 여러 줄의 코드를 줄마다 컴퓨터 점자로 적는다.
+/// 시작표는 첫 줄 앞에, 종료표는 마지막 줄 뒤에 한 번만 적는다.
+pub fn encode_computer_lines(code: &str) -> Result<Vec<Vec<u8>>, BraillifyError> {
+    let mut lines = code
+        .lines()
+        .map(encode_computer)
+        .collect::<Result<Vec<_>, _>>()?;
+    if lines.is_empty() {
+        lines.push(vec![]);
+    }
+    lines[0].splice(0..0, START);
+    lines.last_mut().unwrap().extend(END);
+    Ok(lines)
+}
+
+/// 백틱(`)으로 감싼 코드를 찾아 (앞부분, 코드, 뒷부분)으로 나눈다.
+fn split_code_span(word: &str) -> Option<(&str, &str, &str)> {
+    let open = word.find('`')?;
+    let close = open + 1 + word[open + 1..].find('`')?;
+    Some((&word[..open], &word[open + 1..close], &word[close + 1..]))
+}
+
+/// 단어에서 컴퓨터 점자로 적을 부분을 찾아 (앞부분, 내용, 뒷부분)으로 나눈다.
+/// 백틱으로 감싼 코드와 인터넷 주소, 전자 우편 주소, 파일 경로를 찾는다.
+pub fn split_computer_span(word: &str) -> Option<(&str, &str, &str)> {
+    if let Some(span) = split_code_span(word) {
+        return Some(span);
+    }
+    ADDRESS_REGEX
+        .find_iter(word)
+        // 로마자나 숫자에 이어진 부분은 주소의 시작이 아니다. (a/b/c, 1/2)
+        .find(|m| {
+            !word[..m.start()]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '/')
+        })
+        .and_then(|m| {
+            let span = m.as_str().trim_end_matches(TRAILING_PUNCTUATION);
+            let end = m.start() + span.len();
+            (!span.is_empty()).then(|| (&word[..m.start()], span, &word[end..]))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(text: &str) -> String {
+        encode_computer(text)
+            .unwrap()
+            .iter()
+            .map(|c| encode_unicode(*c))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_encode_computer() {
+        assert_eq!(to_unicode("https"), "⠓⠞⠞⠏⠎");
+        assert_eq!(to_unicode("a@b.c"), "⠁⠈⠃⠨⠉");
+        assert_eq!(to_unicode("A_1"), "⠠⠁⠸⠂");
+        assert_eq!(to_unicode("a_1"), "⠁⠸⠂");
+        assert_eq!(to_unicode("{}|~`"), "⠠⠪⠠⠻⠠⠳⠠⠘⠠⠈");
+        assert_eq!(to_unicode("[]\\^@"), "⠪⠻⠳⠘⠈");
+        assert_eq!(to_unicode("a,b"), "⠁⠠⠠⠃");
+        assert_eq!(to_unicode("aB"), "⠁⠠⠃");
+        assert_eq!(to_unicode("#\\/"), "⠼⠳⠌");
+        assert_eq!(encode_computer_span("x").unwrap(), vec![32, 54, 45, 54, 4]);
+    }
+
+    #[test]
+    pub fn test_split_computer_span() {
+        assert_eq!(
+            split_computer_span("https://example.com에서"),
+            Some(("", "https://example.com", "에서"))
+        );
+        assert_eq!(
+            split_computer_span("(www.example.com)."),
+            Some(("(", "www.example.com", ")."))
+        );
+        assert_eq!(
+            split_computer_span("user@example.co.kr로"),
+            Some(("", "user@example.co.kr", "로"))
+        );
+        assert_eq!(
+            split_computer_span("C:\\Users\\a.txt를"),
+            Some(("", "C:\\Users\\a.txt", "를"))
+        );
+        assert_eq!(
+            split_computer_span("/usr/bin/에"),
+            Some(("", "/usr/bin/", "에"))
+        );
+        assert_eq!(split_computer_span("`a | b`를"), Some(("", "a | b", "를")));
+        assert_eq!(split_computer_span("사과/배"), None);
+        assert_eq!(split_computer_span("1/2"), None);
+        assert_eq!(split_computer_span("a/b/c"), None);
+        assert_eq!(split_computer_span("/"), None);
+        assert_eq!(split_computer_span("//"), None);
+        assert_eq!(split_computer_span("디디었다./디뎠다."), None);
+        assert_eq!(
+            split_computer_span("./run.sh로"),
+            Some(("", "./run.sh", "로"))
+        );
+    }
+}
@@ -0,0 +1,731 @@
+use std::collections::HashMap;
+
+use crate::{
+    archive::{Archive, find_all},
+    book::{Block, BookConfig, Volume, format_book},
+    error::BraillifyError,
+    rich::{Run, math_run, note_paragraph, push_text, trim_runs},
+    table::{Table, TableCell},
+    xml::{XmlElement, XmlNode, parse_xml},
+};
+
+fn child<'a>(element: &'a XmlElement, name: &str) -> Option<&'a XmlElement> {
+    element.elements().find(|e| e.local_name() == name)
+}
+
+fn value<'a>(element: &'a XmlElement, name: &str) -> Option<&'a str> {
+    child(element, name)?.attribute("val")
+}
+
+/// `<w:b/>`, `<w:b w:val="0"/>`처럼 켜고 끄는 글자 속성
+fn toggle(properties: &XmlElement, name: &str) -> Option<bool> {
+    child(properties, name)
+        .map(|e| !matches!(e.attribute("val"), Some("0" | "false" | "off" | "none")))
+}
+
+/// `<w:rPr>`의 서식을 `base` 위에 덮어쓴다.
+fn run_style(properties: Option<&XmlElement>, base: &Run) -> Run {
+    let Some(properties) = properties else {
+        return base.clone();
+    };
+    Run {
+        bold: toggle(properties, "b").unwrap_or(base.bold),
+        italic: toggle(properties, "i").unwrap_or(base.italic),
+        underline: toggle(properties, "u").unwrap_or(base.underline),
+        strike: toggle(properties, "strike")
+            .or(toggle(properties, "dstrike"))
+            .unwrap_or(base.strike),
+        ..base.clone()
+    }
+}
+
+/// `styles.xml`의 스타일
+#[derive(Default)]
+struct Style {
+    /// "heading 1" 또는 개요 수준으로 정한 제목의 단계
+    heading: Option<u8>,
+    run: Run,
+}
+
+fn styles(nodes: &[XmlNode]) -> HashMap<String, Style> {
+    find_all(nodes, "style")
+        .into_iter()
+        .filter_map(|style| {
+            let name = value(style, "name").unwrap_or_default().to_lowercase();
+            let heading = name
+                .strip_prefix("heading ")
+                .and_then(|level| level.trim().parse::<u8>().ok())
+                .or_else(|| {
+                    let level = value(child(style, "pPr")?, "outlineLvl")?
+                        .parse::<u8>()
+                        .ok()?;
+                    // 9는 본문 수준이다.
+                    (level < 9).then_some(level + 1)
+                })
+                .map(|level| level.clamp(1, 6));
+            Some((
+                style.attribute("styleId")?.to_string(),
+                Style {
+                    heading,
+                    run: run_style(child(style, "rPr"), &Run::default()),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// `numbering.xml`에서 (목록 번호, 수준)마다 번호 형식과 시작 번호를 찾는다.
+fn numbering(nodes: &[XmlNode]) -> HashMap<(String, u8), (String, u32)> {
+    let abstracts = find_all(nodes, "abstractNum")
+        .into_iter()
+        .filter_map(|abstract_num| Some((abstract_num.attribute("abstractNumId")?, abstract_num)))
+        .collect::<HashMap<_, _>>();
+    let mut formats = HashMap::new();
+    for num in find_all(nodes, "num") {
+        let (Some(id), Some(abstract_num)) = (
+            num.attribute("numId"),
+            value(num, "abstractNumId").and_then(|id| abstracts.get(id)),
+        ) else {
+            continue;
+        };
+        for lvl in abstract_num.elements().filter(|e| e.local_name() == "lvl") {
+            let Some(level) = lvl.attribute("ilvl").and_then(|l| l.parse().ok()) else {
+                continue;
+            };
+            let format = value(lvl, "numFmt").unwrap_or("decimal").to_string();
+            let start = value(lvl, "start")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(1);
+            formats.insert((id.to_string(), level), (format, start));
+        }
+    }
+    formats
+}
+
+/// 번호 매기기 형식에 맞추어 목록 번호를 적는다. 글머리표는 적지 않는다.
+fn list_number(format: &str, number: u32) -> Option<String> {
+    const GANADA: [char; 14] = [
+        '가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하',
+    ];
+    let letter = |letters: &[char]| {
+        letters
+            .get((number.max(1) as usize - 1) % letters.len())
+            .map(char::to_string)
+    };
+    match format {
+        "bullet" | "none" => None,
+        "lowerLetter" => letter(&('a'..='z').collect::<Vec<_>>()),
+        "upperLetter" => letter(&('A'..='Z').collect::<Vec<_>>()),
+        "ganada" => letter(&GANADA),
+        _ => Some(number.to_string()),
+    }
+}
+
+/// OMML 요소의 자식을 MathML로 옮긴다.
+fn omml_children(element: &XmlElement) -> String {
+    element.elements().map(omml).collect()
+}
+
+/// `<m:e>`, `<m:num>`처럼 이름으로 찾은 인자를 `<mrow>` 하나로 옮긴다.
+fn omml_argument(element: &XmlElement, name: &str) -> String {
+    format!(
+        "<mrow>{}</mrow>",
+        child(element, name).map(omml_children).unwrap_or_default()
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// `<m:t>`의 글을 숫자, 로마자, 연산자 토큰으로 나눈다.
+fn omml_text(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut mathml = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        i += 1;
+        if c.is_whitespace() {
+            continue;
+        }
+        let tag = if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            "mn"
+        } else if c.is_alphabetic() {
+            while i < chars.len() && chars[i].is_ascii_alphabetic() && c.is_ascii_alphabetic() {
+                i += 1;
+            }
+            "mi"
+        } else {
+            "mo"
+        };
+        let token = chars[start..i].iter().collect::<String>();
+        mathml.push_str(&format!("<{tag}>{}</{tag}>", escape(&token)));
+    }
+    mathml
+}
+
+/// OMML(`<m:oMath>`)을 MathML로 옮긴다.
+fn omml(element: &XmlElement) -> String {
+    let attribute = |properties: &str, name: &str, default: &str| {
+        child(element, properties)
+            .and_then(|pr| child(pr, name))
+            .map(|e| e.attribute("val").unwrap_or_default().to_string())
+            .unwrap_or(default.to_string())
+    };
+    let empty = |name: &str| child(element, name).is_none_or(|e| e.elements().next().is_none());
+    match element.local_name() {
+        "r" => omml_text(
+            &element
+                .elements()
+                .filter(|e| e.local_name() == "t")
+                .map(XmlElement::text)
+                .collect::<String>(),
+        ),
+        "f" => format!(
+            "<mfrac>{}{}</mfrac>",
+            omml_argument(element, "num"),
+            omml_argument(element, "den")
+        ),
+        "sSup" => format!(
+            "<msup>{}{}</msup>",
+            omml_argument(element, "e"),
+            omml_argument(element, "sup")
+        ),
+        "sSub" => format!(
+            "<msub>{}{}</msub>",
+            omml_argument(element, "e"),
+            omml_argument(element, "sub")
+        ),
+        "sSubSup" => format!(
+            "<msubsup>{}{}{}</msubsup>",
+            omml_argument(element, "e"),
+            omml_argument(element, "sub"),
+            omml_argument(element, "sup")
+        ),
+        "rad" if empty("deg") => format!("<msqrt>{}</msqrt>", omml_argument(element, "e")),
+        "rad" => format!(
+            "<mroot>{}{}</mroot>",
+            omml_argument(element, "e"),
+            omml_argument(element, "deg")
+        ),
+        "d" => format!(
+            "<mfenced open=\"{}\" close=\"{}\" separators=\"{}\">{}</mfenced>",
+            escape(&attribute("dPr", "begChr", "(")),
+            escape(&attribute("dPr", "endChr", ")")),
+            escape(&attribute("dPr", "sepChr", "|")),
+            element
+                .elements()
+                .filter(|e| e.local_name() == "e")
+                .map(|e| format!("<mrow>{}</mrow>", omml_children(e)))
+                .collect::<String>()
+        ),
+        "nary" => {
+            let operator = format!("<mo>{}</mo>", escape(&attribute("naryPr", "chr", "∫")));
+            let operator = if empty("sub") && empty("sup") {
+                operator
+            } else {
+                format!(
+                    "<munderover>{operator}{}{}</munderover>",
+                    omml_argument(element, "sub"),
+                    omml_argument(element, "sup")
+                )
+            };
+            format!("{operator}{}", omml_argument(element, "e"))
+        }
+        "limLow" => format!(
+            "<munder>{}{}</munder>",
+            omml_argument(element, "e"),
+            omml_argument(element, "lim")
+        ),
+        "limUpp" => format!(
+            "<mover>{}{}</mover>",
+            omml_argument(element, "e"),
+            omml_argument(element, "lim")
+        ),
+        "eqArr" => format!(
+            "<mtable>{}</mtable>",
+            element
+                .elements()
+                .filter(|e| e.local_name() == "e")
+                .map(|e| format!("<mtr><mtd>{}</mtd></mtr>", omml_children(e)))
+                .collect::<String>()
+        ),
+        "m" => format!(
+            "<mtable>{}</mtable>",
+            element
+                .elements()
+                .filter(|e| e.local_name() == "mr")
+                .map(|row| format!(
+                    "<mtr>{}</mtr>",
+                    row.elements()
+                        .filter(|e| e.local_name() == "e")
+                        .map(|e| format!("<mtd>{}</mtd>", omml_children(e)))
+                        .collect::<String>()
+                ))
+                .collect::<String>()
+        ),
+        // 악센트, 윗줄처럼 점자에 없는 꾸밈은 밑바탕만 적는다.
+        "acc" | "bar" | "groupChr" | "borderBox" | "box" | "phant" => omml_argument(element, "e"),
+        name if name.ends_with("Pr") => String::new(),
+        _ => format!("<mrow>{}</mrow>", omml_children(element)),
+    }
+}
+
+/// 수식을 MathML 런으로 만든다. 옮기지 못하면 수식의 글을 점역자 주로 적는다.
+fn omml_run(element: &XmlElement) -> Run {
+    let mathml = format!("<math>{}</math>", omml_children(element));
+    let text = find_all(&element.children, "t")
+        .iter()
+        .map(|t| t.text())
+        .collect::<String>();
+    math_run(&mathml, &text)
+}
+
+/// 표 칸 안의 글을 서식 없이 이어 붙인다.
+fn plain_text(element: &XmlElement) -> String {
+    find_all(&element.children, "p")
+        .iter()
+        .map(|p| {
+            find_all(&p.children, "t")
+                .iter()
+                .map(|t| t.text())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `<w:tbl>`. 세로로 합친 칸(`vMerge`)은 첫 칸의 `row_span`으로 센다.
+fn parse_table(tbl: &XmlElement) -> Table {
+    let mut table = Table::default();
+    let mut in_header = true;
+    // 열 위치마다 세로 병합이 시작된 칸의 (행, 칸) 위치
+    let mut merges: HashMap<usize, (usize, usize)> = HashMap::new();
+    for tr in tbl.elements().filter(|e| e.local_name() == "tr") {
+        if in_header && tr_header(tr) {
+            table.header_rows += 1;
+        } else {
+            in_header = false;
+        }
+        let row_index = table.rows.len();
+        let mut row = vec![];
+        let mut column = 0;
+        for tc in tr.elements().filter(|e| e.local_name() == "tc") {
+            let properties = child(tc, "tcPr");
+            let col_span = properties
+                .and_then(|pr| value(pr, "gridSpan"))
+                .and_then(|span| span.parse().ok())
+                .filter(|span| *span > 0)
+                .unwrap_or(1);
+            let merge = properties
+                .and_then(|pr| child(pr, "vMerge"))
+                .map(|merge| merge.attribute("val").unwrap_or("continue"));
+            match merge {
+                Some("continue") => {
+                    if let Some((r, c)) = merges.get(&column) {
+                        table.rows[*r][*c].row_span += 1;
+                    }
+                }
+                _ => {
+                    if merge == Some("restart") {
+                        merges.insert(column, (row_index, row.len()));
+                    } else {
+                        merges.remove(&column);
+                    }
+                    row.push(TableCell {
+                        col_span,
+                        ..TableCell::new(plain_text(tc))
+                    });
+                }
+            }
+            column += col_span;
+        }
+        table.rows.push(row);
+    }
+    table
+}
+
+/// 쪽마다 되풀이하는 머리글 행(`<w:tblHeader/>`)인지 여부
+fn tr_header(tr: &XmlElement) -> bool {
+    child(tr, "trPr").and_then(|pr| toggle(pr, "tblHeader")) == Some(true)
+}
+
+/// `document.xml`의 문단을 `Block`으로 모은다.
+struct Builder {
+    blocks: Vec<Block>,
+    styles: HashMap<String, Style>,
+    numbering: HashMap<(String, u8), (String, u32)>,
+    /// (목록 번호, 수준)마다 지금까지 매긴 번호
+    counters: HashMap<(String, u8), u32>,
+    /// `footnote:1`, `endnote:1`처럼 종류와 번호로 찾는 각주와 미주
+    footnotes: HashMap<String, XmlElement>,
+    /// 문서에 나온 각주의 수
+    footnote_count: usize,
+}
+
+impl Builder {
+    /// `<w:body>`나 표 밖의 콘텐츠 컨트롤 안의 문단과 표
+    fn body(&mut self, nodes: &[XmlNode]) {
+        for node in nodes {
+            let XmlNode::Element(element) = node else {
+                continue;
+            };
+            match element.local_name() {
+                "p" => self.paragraph(element),
+                "tbl" => self.blocks.push(Block::Table(parse_table(element))),
+                "sdt" | "sdtContent" | "customXml" => self.body(&element.children),
+                _ => {}
+            }
+        }
+    }
+
+    /// 목록 번호를 하나 올리고 하위 수준의 번호는 처음으로 되돌린다.
+    fn next_number(&mut self, id: &str, level: u8) -> Option<String> {
+        let (format, start) = self.numbering.get(&(id.to_string(), level))?.clone();
+        self.counters
+            .retain(|(counter_id, counter_level), _| counter_id != id || *counter_level <= level);
+        let number = self
+            .counters
+            .entry((id.to_string(), level))
+            .and_modify(|n| *n += 1)
+            .or_insert(start);
+        list_number(&format, *number)
+    }
+
+    fn paragraph(&mut self, p: &XmlElement) {
+        let properties = child(p, "pPr");
+        let style = properties
+            .and_then(|pr| value(pr, "pStyle"))
+            .and_then(|id| self.styles.get(id));
+        let base = style.map(|style| style.run.clone()).unwrap_or_default();
+        let heading = properties
+            .and_then(|pr| value(pr, "outlineLvl"))
+            .and_then(|level| level.parse::<u8>().ok())
+            .filter(|level| *level < 9)
+            .map(|level| (level + 1).min(6))
+            .or(style.and_then(|style| style.heading));
+        let list = properties
+            .and_then(|pr| child(pr, "numPr"))
+            .and_then(|num| Some((value(num, "numId")?, value(num, "ilvl").unwrap_or("0"))))
+            .filter(|(id, _)| *id != "0")
+            .map(|(id, level)| (id.to_string(), level.parse::<u8>().unwrap_or(0)));
+
+        let mut runs = vec![];
+        if let Some((id, level)) = &list
+            && let Some(number) = self.next_number(id, *level)
+        {
+            runs.push(Run::new(format!("{number}. ")));
+        }
+        let mut notes = vec![];
+        self.inline(&p.children, &base, &mut runs, &mut notes);
+        let runs = trim_runs(runs);
+        if !runs.is_empty() {
+            self.blocks.push(match (heading, list) {
+                (Some(level), _) => Block::Heading { level, runs },
+                (None, Some((_, depth))) => Block::ListItem {
+                    depth: depth as usize,
+                    runs,
+                },
+                (None, None) => Block::Paragraph(runs),
+            });
+        }
+        for (number, runs) in notes {
+            self.blocks.push(note_paragraph(number, runs));
+        }
+    }
+
+    fn inline(
+        &mut self,
+        nodes: &[XmlNode],
+        base: &Run,
+        runs: &mut Vec<Run>,
+        notes: &mut Vec<(usize, Vec<Run>)>,
+    ) {
+        for node in nodes {
+            let XmlNode::Element(element) = node else {
+                continue;
+            };
+            match element.local_name() {
+                "r" => self.run(element, base, runs, notes),
+                "hyperlink" | "ins" | "smartTag" | "fldSimple" | "sdt" | "sdtContent"
+                | "customXml" => self.inline(&element.children, base, runs, notes),
+                "oMathPara" | "oMath" => runs.push(omml_run(element)),
+                _ => {}
+            }
+        }
+    }
+
+    fn run(
+        &mut self,
+        r: &XmlElement,
+        base: &Run,
+        runs: &mut Vec<Run>,
+        notes: &mut Vec<(usize, Vec<Run>)>,
+    ) {
+        let properties = child(r, "rPr");
+        let base = properties
+            .and_then(|pr| value(pr, "rStyle"))
+            .and_then(|id| self.styles.get(id))
+            .map(|style| Run {
+                bold: base.bold || style.run.bold,
+                italic: base.italic || style.run.italic,
+                underline: base.underline || style.run.underline,
+                strike: base.strike || style.run.strike,
+                ..base.clone()
+            })
+            .unwrap_or(base.clone());
+        let style = run_style(properties, &base);
+        for element in r.elements() {
+            match element.local_name() {
+                "t" => push_text(runs, &element.text(), &style),
+                "tab" => push_text(runs, " ", &style),
+                "br" | "cr" => push_text(runs, "\n", &style),
+                "noBreakHyphen" => push_text(runs, "-", &style),
+                "footnoteReference" | "endnoteReference" => {
+                    let Some(footnote) = element
+                        .attribute("id")
+                        .and_then(|id| {
+                            let kind = element.local_name().trim_end_matches("Reference");
+                            self.footnotes.get(&format!("{kind}:{id}"))
+                        })
+                        .cloned()
+                    else {
+                        continue;
+                    };
+                    self.footnote_count += 1;
+                    let number = self.footnote_count;
+                    push_text(runs, &format!("{number})"), &Run::default());
+                    let mut note = vec![];
+                    for p in find_all(&footnote.children, "p") {
+                        if !note.is_empty() {
+                            push_text(&mut note, " ", &Run::default());
+                        }
+                        let base = Run::default();
+                        self.inline(&p.children, &base, &mut note, &mut vec![]);
+                    }
+                    notes.push((number, note));
+                }
+                "drawing" | "pict" | "object" => {
+                    let alt = find_all(&element.children, "docPr")
+                        .first()
+                        .and_then(|pr| pr.attribute("descr").or(pr.attribute("title")))
+                        .map(str::trim)
+                        .filter(|alt| !alt.is_empty())
+                        .unwrap_or("그림 생략")
+                        .to_string();
+                    runs.push(Run {
+                        note: true,
+                        ..Run::new(alt)
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 있으면 읽고 없으면 빈 문서로 본다.
+fn read_optional(archive: &mut Archive, path: &str) -> Result<Vec<XmlNode>, BraillifyError> {
+    if archive.contains(path) {
+        parse_xml(&archive.read(path)?)
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// DOCX(WordprocessingML) 문서를 본문 순서대로 읽어 점자 책의 덩어리로 나눈다.
+/// 제목 스타일은 제목으로, 번호 매기기 문단은 목록으로, 각주는 문단 뒤의 글로 적는다.
+pub fn parse_docx(bytes: &[u8]) -> Result<Vec<Block>, BraillifyError> {
+    let mut archive = Archive::new(bytes, "DOCX")?;
+    let document = parse_xml(&archive.read("word/document.xml")?)?;
+    let style_nodes = read_optional(&mut archive, "word/styles.xml")?;
+    let numbering_nodes = read_optional(&mut archive, "word/numbering.xml")?;
+    let footnote_nodes = read_optional(&mut archive, "word/footnotes.xml")?;
+    let endnote_nodes = read_optional(&mut archive, "word/endnotes.xml")?;
+    let mut builder = Builder {
+        blocks: vec![],
+        styles: styles(&style_nodes),
+        numbering: numbering(&numbering_nodes),
+        counters: HashMap::new(),
+        footnotes: find_all(&footnote_nodes, "footnote")
+            .into_iter()
+            .chain(find_all(&endnote_nodes, "endnote"))
+            // 구분선 같은 특수 각주는 건너뛴다.
+            .filter(|note| note.attribute("type").is_none_or(|t| t == "normal"))
+            .filter_map(|note| {
+                let key = format!("{}:{}", note.local_name(), note.attribute("id")?);
+                Some((key, note.clone()))
+            })
+            .collect(),
+        footnote_count: 0,
+    };
+    let body = find_all(&document, "body");
+    let body = body
+        .first()
+        .ok_or_else(|| archive.error("body not found"))?;
+    builder.body(&body.children);
+    Ok(builder.blocks)
+}
+
+/// DOCX 문서를 점자 책으로 배치한다.
+pub fn encode_docx(bytes: &[u8], config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_docx(bytes)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::archive::build_zip;
+
+    const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/></w:style>
+  <w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:pPr><w:outlineLvl w:val="0"/></w:pPr></w:style>
+  <w:style w:type="character" w:styleId="Strong"><w:name w:val="Strong"/><w:rPr><w:b/></w:rPr></w:style>
+</w:styles>"#;
+
+    const NUMBERING: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:abstractNum w:abstractNumId="0">
+    <w:lvl w:ilvl="0"><w:start w:val="1"/><w:numFmt w:val="decimal"/></w:lvl>
+    <w:lvl w:ilvl="1"><w:numFmt w:val="bullet"/></w:lvl>
+  </w:abstractNum>
+  <w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+</w:numbering>"#;
+
+    const FOOTNOTES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:footnotes xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:footnote w:type="separator" w:id="-1"><w:p><w:r><w:separator/></w:r></w:p></w:footnote>
+  <w:footnote w:id="1"><w:p><w:r><w:rPr><w:rStyle w:val="FootnoteReference"/></w:rPr><w:footnoteRef/></w:r><w:r><w:t xml:space="preserve"> 대한민국의 수도</w:t></w:r></w:p></w:footnote>
+</w:footnotes>"#;
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+<w:body>
+  <w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>제목</w:t></w:r></w:p>
+  <w:p><w:r><w:t xml:space="preserve">서울은 </w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>수도</w:t></w:r><w:r><w:rPr><w:u w:val="single"/></w:rPr><w:t>이다</w:t></w:r><w:r><w:rPr><w:vertAlign w:val="superscript"/></w:rPr><w:footnoteReference w:id="1"/></w:r><w:r><w:t>.</w:t></w:r></w:p>
+  <w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>사과</w:t></w:r></w:p>
+  <w:p><w:pPr><w:numPr><w:ilvl w:val="1"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:rPr><w:rStyle w:val="Strong"/></w:rPr><w:t>부사</w:t></w:r></w:p>
+  <w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>배</w:t></w:r></w:p>
+  <w:p><w:r><w:t xml:space="preserve">값은 </w:t></w:r><m:oMath><m:f><m:num><m:r><m:t>1</m:t></m:r></m:num><m:den><m:r><m:t>2</m:t></m:r></m:den></m:f></m:oMath></w:p>
+  <w:tbl>
+    <w:tr><w:trPr><w:tblHeader/></w:trPr><w:tc><w:p><w:r><w:t>과일</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>값</w:t></w:r></w:p></w:tc></w:tr>
+    <w:tr><w:tc><w:tcPr><w:vMerge w:val="restart"/></w:tcPr><w:p><w:r><w:t>사과</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>1</w:t></w:r></w:p></w:tc></w:tr>
+    <w:tr><w:tc><w:tcPr><w:vMerge/></w:tcPr><w:p/></w:tc><w:tc><w:p><w:r><w:t>2</w:t></w:r></w:p></w:tc></w:tr>
+    <w:tr><w:tc><w:tcPr><w:gridSpan w:val="2"/></w:tcPr><w:p><w:r><w:t>합계</w:t></w:r></w:p></w:tc></w:tr>
+  </w:tbl>
+  <w:sectPr/>
+</w:body>
+</w:document>"#;
+
+    #[test]
+    pub fn test_parse_docx() {
+        let docx = build_zip(&[
+            ("word/document.xml", DOCUMENT),
+            ("word/styles.xml", STYLES),
+            ("word/numbering.xml", NUMBERING),
+            ("word/footnotes.xml", FOOTNOTES),
+        ]);
+        assert_eq!(
+            parse_docx(&docx).unwrap(),
+            vec![
+                Block::heading(1, "제목"),
+                Block::Paragraph(vec![
+                    Run::new("서울은 "),
+                    Run {
+                        bold: true,
+                        ..Run::new("수도")
+                    },
+                    Run {
+                        underline: true,
+                        ..Run::new("이다")
+                    },
+                    Run::new("1)."),
+                ]),
+                Block::paragraph("1) 대한민국의 수도"),
+                Block::list_item(0, "1. 사과"),
+                Block::ListItem {
+                    depth: 1,
+                    runs: vec![Run {
+                        bold: true,
+                        ..Run::new("부사")
+                    }],
+                },
+                Block::list_item(0, "2. 배"),
+                Block::Paragraph(vec![
+                    Run::new("값은 "),
+                    Run {
+                        math: true,
+                        ..Run::new(
+                            "<math><mfrac><mrow><mn>1</mn></mrow><mrow><mn>2</mn></mrow></mfrac></math>"
+                        )
+                    },
+                ]),
+                Block::Table(Table {
+                    rows: vec![
+                        vec![TableCell::new("과일"), TableCell::new("값")],
+                        vec![
+                            TableCell {
+                                row_span: 2,
+                                ..TableCell::new("사과")
+                            },
+                            TableCell::new("1"),
+                        ],
+                        vec![TableCell::new("2")],
+                        vec![TableCell {
+                            col_span: 2,
+                            ..TableCell::new("합계")
+                        }],
+                    ],
+                    header_rows: 1,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_omml() {
+        let nodes = parse_xml(
+            r#"<m:oMath><m:rad><m:radPr><m:degHide m:val="1"/></m:radPr><m:deg/><m:e><m:r><m:t>x+1</m:t></m:r></m:e></m:rad></m:oMath>"#,
+        )
+        .unwrap();
+        let XmlNode::Element(math) = &nodes[0] else {
+            panic!()
+        };
+        assert_eq!(
+            omml_children(math),
+            "<msqrt><mrow><mi>x</mi><mo>+</mo><mn>1</mn></mrow></msqrt>"
+        );
+        // 점자로 옮기지 못하는 수식은 글을 점역자 주로 적는다.
+        let nodes = parse_xml(r#"<m:oMath><m:r><m:t>가</m:t></m:r></m:oMath>"#).unwrap();
+        let XmlNode::Element(math) = &nodes[0] else {
+            panic!()
+        };
+        assert_eq!(
+            omml_run(math),
+            Run {
+                note: true,
+                ..Run::new("수식 가")
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_parse_docx_error() {
+        assert!(matches!(
+            parse_docx(&build_zip(&[("word/styles.xml", STYLES)])),
+            Err(BraillifyError::DocumentError { .. })
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use phf::phf_map;
+use unicode_normalization::UnicodeNormalization;
 
 use crate::{error::BraillifyError, unicode::decode_unicode};
 
@@ -37,3 +38,191 @@ pub fn encode_english(text: char) -> Result<u8, BraillifyError> {
     }
     Err(BraillifyError::InvalidEnglishCharacter { character: text, position: None })
 }
+
+/// 통일영어점자 규정 4.2 - 수식 기호(modifier)는 수식되는 글자 바로 앞에 적는다.
+const ACUTE: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠌')];
+const GRAVE: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠡')];
+const CIRCUMFLEX: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠩')];
+const TILDE: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠻')];
+const DIAERESIS: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠒')];
+const CEDILLA: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠯')];
+const RING: &[u8] = &[decode_unicode('⠈'), decode_unicode('⠫')];
+const CARON: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠬')];
+const MACRON: &[u8] = &[decode_unicode('⠈'), decode_unicode('⠤')];
+const BREVE: &[u8] = &[decode_unicode('⠈'), decode_unicode('⠬')];
+/// 규정의 표에 없는 윗점과 오고넥은 ⠘⠆, ⠘⠣으로 적고, 이중 양음 부호는 양음 부호를 두 번 적는다.
+const DOT_ABOVE: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠆')];
+const OGONEK: &[u8] = &[decode_unicode('⠘'), decode_unicode('⠣')];
+const DOUBLE_ACUTE: &[u8] = &[
+    decode_unicode('⠘'),
+    decode_unicode('⠌'),
+    decode_unicode('⠘'),
+    decode_unicode('⠌'),
+];
+/// 통일영어점자 규정 4.2.4 - 합자(ligature)는 두 글자 사이에 ⠘⠖을 적는다.
+const LIGATURE: u8 = decode_unicode('⠖');
+
+/// 분해되지 않는 글자의 점형. 빗금이 그어진 글자는 수식 기호 ⠈⠡을 앞에 적는다.
+/// 점 없는 i와 엥(ŋ)은 ß처럼 ⠈ 뒤에 바탕 글자를 적는다.
+static SPECIAL_LATIN_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    'ø' => &[decode_unicode('⠈'), decode_unicode('⠡'), decode_unicode('⠕')],
+    'đ' => &[decode_unicode('⠈'), decode_unicode('⠡'), decode_unicode('⠙')],
+    'ħ' => &[decode_unicode('⠈'), decode_unicode('⠡'), decode_unicode('⠓')],
+    'ł' => &[decode_unicode('⠈'), decode_unicode('⠡'), decode_unicode('⠇')],
+    'ŧ' => &[decode_unicode('⠈'), decode_unicode('⠡'), decode_unicode('⠞')],
+    'æ' => &[decode_unicode('⠁'), decode_unicode('⠘'), LIGATURE, decode_unicode('⠑')],
+    'œ' => &[decode_unicode('⠕'), decode_unicode('⠘'), LIGATURE, decode_unicode('⠑')],
+    'ß' => &[decode_unicode('⠈'), decode_unicode('⠎')],
+    'ı' => &[decode_unicode('⠈'), decode_unicode('⠊')],
+    'ŋ' => &[decode_unicode('⠈'), decode_unicode('⠝')],
+};
+
+fn modifier_for_mark(mark: char) -> Option<&'static [u8]> {
+    match mark {
+        '\u{0301}' => Some(ACUTE),
+        '\u{0300}' => Some(GRAVE),
+        '\u{0302}' => Some(CIRCUMFLEX),
+        '\u{0303}' => Some(TILDE),
+        '\u{0308}' => Some(DIAERESIS),
+        '\u{0327}' => Some(CEDILLA),
+        '\u{030A}' => Some(RING),
+        '\u{030C}' => Some(CARON),
+        '\u{0304}' => Some(MACRON),
+        '\u{0306}' => Some(BREVE),
+        '\u{0307}' => Some(DOT_ABOVE),
+        '\u{0328}' => Some(OGONEK),
+        '\u{030B}' => Some(DOUBLE_ACUTE),
+        _ => None,
+    }
+}
+
+/// 라틴 문자 보충(Latin-1 Supplement)과 라틴 확장-A(Latin Extended-A)의 글자를
+/// 수식 기호와 기본 글자로 나눈다.
+fn split_accented(c: char) -> Option<(&'static [u8], char)> {
+    if !('\u{00C0}'..='\u{017F}').contains(&c) || !c.is_alphabetic() {
+        return None;
+    }
+    let mut decomposed = c.nfd();
+    let base = decomposed.next()?;
+    let mark = decomposed.next()?;
+    if !base.is_ascii_alphabetic() || decomposed.next().is_some() {
+        return None;
+    }
+    Some((modifier_for_mark(mark)?, base))
+}
+
+/// 수식 기호가 붙은 로마자인지 확인한다.
+pub fn is_accented_letter(c: char) -> bool {
+    SPECIAL_LATIN_MAP.contains_key(&c.to_lowercase().next().unwrap_or(c))
+        || split_accented(c).is_some()
+}
+
+/// 로마자(수식 기호가 붙은 글자 포함)인지 확인한다.
+pub fn is_english_letter(c: char) -> bool {
+    c.is_ascii_alphabetic() || is_accented_letter(c)
+}
+
+/// 로마자 한 글자를 점형으로 바꾼다. 수식 기호가 붙은 글자는 수식 기호를 기본 글자 앞에 적는다.
+/// 약자 표는 ASCII 글자로만 이루어져 있으므로 수식 기호가 붙은 글자는 약자에 포함되지 않는다.
+pub fn encode_english_letter(c: char) -> Result<Vec<u8>, BraillifyError> {
+    if c.is_ascii_alphabetic() {
+        return Ok(vec![encode_english(c)?]);
+    }
+    if let Some(code) = SPECIAL_LATIN_MAP.get(&c.to_lowercase().next().unwrap_or(c)) {
+        return Ok(code.to_vec());
+    }
+    if let Some((modifier, base)) = split_accented(c) {
+        let mut result = modifier.to_vec();
+        result.push(encode_english(base)?);
+        return Ok(result);
+    }
+    Err(BraillifyError::InvalidEnglishCharacter { character: c, position: None })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_is_english_letter() {
+        assert!(is_english_letter('a'));
+        assert!(is_english_letter('é'));
+        assert!(is_english_letter('Ü'));
+        assert!(is_english_letter('ß'));
+        assert!(is_english_letter('Ø'));
+        assert!(is_english_letter('ą'));
+        assert!(is_english_letter('Ż'));
+        assert!(is_english_letter('ő'));
+        assert!(is_english_letter('ı'));
+        assert!(is_english_letter('Ŋ'));
+        assert!(!is_english_letter('×'));
+        assert!(!is_english_letter('÷'));
+        assert!(!is_english_letter('가'));
+        assert!(!is_english_letter('1'));
+    }
+
+    #[test]
+    pub fn test_encode_english_letter() {
+        assert_eq!(encode_english_letter('a').unwrap(), vec![decode_unicode('⠁')]);
+        assert_eq!(
+            encode_english_letter('é').unwrap(),
+            vec![decode_unicode('⠘'), decode_unicode('⠌'), decode_unicode('⠑')]
+        );
+        assert_eq!(
+            encode_english_letter('Ü').unwrap(),
+            vec![decode_unicode('⠘'), decode_unicode('⠒'), decode_unicode('⠥')]
+        );
+        assert_eq!(
+            encode_english_letter('ç').unwrap(),
+            vec![decode_unicode('⠘'), decode_unicode('⠯'), decode_unicode('⠉')]
+        );
+        assert_eq!(
+            encode_english_letter('å').unwrap(),
+            vec![decode_unicode('⠈'), decode_unicode('⠫'), decode_unicode('⠁')]
+        );
+        assert_eq!(
+            encode_english_letter('Ů').unwrap(),
+            vec![decode_unicode('⠈'), decode_unicode('⠫'), decode_unicode('⠥')]
+        );
+        assert_eq!(
+            encode_english_letter('ã').unwrap(),
+            vec![decode_unicode('⠘'), decode_unicode('⠻'), decode_unicode('⠁')]
+        );
+        assert_eq!(
+            encode_english_letter('Æ').unwrap(),
+            vec![
+                decode_unicode('⠁'),
+                decode_unicode('⠘'),
+                decode_unicode('⠖'),
+                decode_unicode('⠑')
+            ]
+        );
+        assert_eq!(
+            encode_english_letter('ę').unwrap(),
+            vec![decode_unicode('⠘'), decode_unicode('⠣'), decode_unicode('⠑')]
+        );
+        assert_eq!(
+            encode_english_letter('ė').unwrap(),
+            vec![decode_unicode('⠘'), decode_unicode('⠆'), decode_unicode('⠑')]
+        );
+        assert_eq!(
+            encode_english_letter('ű').unwrap(),
+            vec![
+                decode_unicode('⠘'),
+                decode_unicode('⠌'),
+                decode_unicode('⠘'),
+                decode_unicode('⠌'),
+                decode_unicode('⠥')
+            ]
+        );
+        assert_eq!(
+            encode_english_letter('ı').unwrap(),
+            vec![decode_unicode('⠈'), decode_unicode('⠊')]
+        );
+        assert_eq!(
+            encode_english_letter('ŋ').unwrap(),
+            vec![decode_unicode('⠈'), decode_unicode('⠝')]
+        );
+        assert!(encode_english_letter('가').is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    char_struct::KoreanChar, error::BraillifyError, jauem::jongseong::encode_jongseong,
+    korean_char::encode_korean_char, number, symbol_shortcut::encode_char_symbol_shortcut,
+    unicode::decode_unicode,
+};
+
+/// 원문자 시작표 (○ ⠸⠴⠇의 여는 부분)
+const CIRCLE_START: [u8; 2] = [decode_unicode('⠸'), decode_unicode('⠴')];
+/// 원문자 종료표
+const CIRCLE_END: u8 = decode_unicode('⠇');
+
+const CONSONANTS: [char; 14] = [
+    'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+const SYLLABLES: [char; 14] = [
+    '가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Enclosure {
+    /// ①, ㉠, ⓐ
+    Circle,
+    /// ⑴, ㈀, ⒜
+    Parenthesis,
+    /// ⒈
+    FullStop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Content {
+    Number(u32),
+    Consonant(char),
+    Syllable(char),
+    Letter(char),
+}
+
+fn offset(c: char, start: char) -> usize {
+    (c as u32 - start as u32) as usize
+}
+
+fn parse(c: char) -> Option<(Enclosure, Content)> {
+    use Content::*;
+    use Enclosure::*;
+    Some(match c {
+        '①'..='⑳' => (Circle, Number(offset(c, '①') as u32 + 1)),
+        '㉑'..='㉟' => (Circle, Number(offset(c, '㉑') as u32 + 21)),
+        '㊱'..='㊿' => (Circle, Number(offset(c, '㊱') as u32 + 36)),
+        '⓪' => (Circle, Number(0)),
+        '⑴'..='⒇' => (Parenthesis, Number(offset(c, '⑴') as u32 + 1)),
+        '⒈'..='⒛' => (FullStop, Number(offset(c, '⒈') as u32 + 1)),
+        '㉠'..='㉭' => (Circle, Consonant(CONSONANTS[offset(c, '㉠')])),
+        '㉮'..='㉻' => (Circle, Syllable(SYLLABLES[offset(c, '㉮')])),
+        '㈀'..='㈍' => (Parenthesis, Consonant(CONSONANTS[offset(c, '㈀')])),
+        '㈎'..='㈛' => (Parenthesis, Syllable(SYLLABLES[offset(c, '㈎')])),
+        'ⓐ'..='ⓩ' => (Circle, Letter(char::from(b'a' + offset(c, 'ⓐ') as u8))),
+        'Ⓐ'..='Ⓩ' => (Circle, Letter(char::from(b'A' + offset(c, 'Ⓐ') as u8))),
+        '⒜'..='⒵' => (Parenthesis, Letter(char::from(b'a' + offset(c, '⒜') as u8))),
+        _ => return None,
+    })
+}
+
+pub fn is_enumerator_char(c: char) -> bool {
+    parse(c).is_some()
+}
+
+fn encode_content(content: Content) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = vec![];
+    match content {
+        Content::Number(n) => {
+            result.push(decode_unicode('⠼'));
+            for digit in n.to_string().chars() {
+                result.push(number::encode_number(digit)?);
+            }
+        }
+        Content::Consonant(c) => {
+            // 제9항 한글의 자음자가 번호로 쓰일 때에는 온표를 앞세워 받침으로 적는다.
+            result.push(decode_unicode('⠿'));
+            result.extend(encode_jongseong(c)?);
+        }
+        Content::Syllable(c) => result.extend(encode_korean_char(&KoreanChar::new(c)?)?),
+        Content::Letter(c) => {
+            if c.is_ascii_uppercase() {
+                result.push(decode_unicode('⠠'));
+            }
+            result.extend(crate::english::encode_english_letter(c)?);
+        }
+    }
+    Ok(result)
+}
+
+/// 원문자, 괄호 문자, 마침표 붙은 숫자 같은 번호 문자를 적는다.
+/// 원문자는 원문자 시작표와 종료표 사이에, 괄호 문자는 소괄호 사이에 내용을 적는다.
+pub fn encode_enumerator(c: char) -> Result<Vec<u8>, BraillifyError> {
+    let Some((enclosure, content)) = parse(c) else {
+        return Err(BraillifyError::InvalidCharacter {
+            character: c,
+            position: None,
+            context: "Unknown enumerator".to_string(),
+        });
+    };
+    let content = encode_content(content)?;
+    let mut result = vec![];
+    match enclosure {
+        Enclosure::Circle => {
+            result.extend(CIRCLE_START);
+            result.extend(content);
+            result.push(CIRCLE_END);
+        }
+        Enclosure::Parenthesis => {
+            result.extend(encode_char_symbol_shortcut('(')?);
+            result.extend(content);
+            result.extend(encode_char_symbol_shortcut(')')?);
+        }
+        Enclosure::FullStop => {
+            result.extend(content);
+            result.extend(encode_char_symbol_shortcut('.')?);
+        }
+    }
+    Ok(result)
+}
+
+fn roman_numeral(c: char) -> Option<&'static str> {
+    const UPPER: [&str; 16] = [
+        "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "L", "C", "D",
+        "M",
+    ];
+    const LOWER: [&str; 16] = [
+        "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x", "xi", "xii", "l", "c", "d",
+        "m",
+    ];
+    match c {
+        'Ⅰ'..='Ⅿ' => Some(UPPER[offset(c, 'Ⅰ')]),
+        'ⅰ'..='ⅿ' => Some(LOWER[offset(c, 'ⅰ')]),
+        _ => None,
+    }
+}
+
+/// 로마 숫자 문자(Ⅲ, ⅳ)는 로마자로 풀어 쓴다. 바꿀 것이 없으면 None
+pub fn expand_roman_numerals(word: &str) -> Option<String> {
+    if !word.chars().any(|c| roman_numeral(c).is_some()) {
+        return None;
+    }
+    Some(
+        word.chars()
+            .map(|c| match roman_numeral(c) {
+                Some(roman) => roman.to_string(),
+                None => c.to_string(),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(c: char) -> String {
+        encode_enumerator(c)
+            .unwrap()
+            .iter()
+            .map(|c| encode_unicode(*c))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_encode_enumerator() {
+        assert_eq!(to_unicode('①'), "⠸⠴⠼⠁⠇");
+        assert_eq!(to_unicode('⑳'), "⠸⠴⠼⠃⠚⠇");
+        assert_eq!(to_unicode('㉑'), "⠸⠴⠼⠃⠁⠇");
+        assert_eq!(to_unicode('⑴'), "⠦⠄⠼⠁⠠⠴");
+        assert_eq!(to_unicode('⒈'), "⠼⠁⠲");
+        assert_eq!(to_unicode('㉠'), "⠸⠴⠿⠁⠇");
+        assert_eq!(to_unicode('㉯'), "⠸⠴⠉⠇");
+        assert_eq!(to_unicode('㈀'), "⠦⠄⠿⠁⠠⠴");
+        assert_eq!(to_unicode('ⓐ'), "⠸⠴⠁⠇");
+        assert_eq!(to_unicode('Ⓑ'), "⠸⠴⠠⠃⠇");
+        assert!(encode_enumerator('1').is_err());
+    }
+
+    #[test]
+    pub fn test_is_enumerator_char() {
+        assert!(is_enumerator_char('②'));
+        assert!(is_enumerator_char('㉡'));
+        assert!(!is_enumerator_char('ㄱ'));
+        assert!(!is_enumerator_char('Ⅲ'));
+    }
+
+    #[test]
+    pub fn test_expand_roman_numerals() {
+        assert_eq!(expand_roman_numerals("Ⅲ장"), Some("III장".to_string()));
+        assert_eq!(expand_roman_numerals("ⅳ."), Some("iv.".to_string()));
+        assert_eq!(expand_roman_numerals("III"), None);
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use crate::{
+    archive::{Archive, find_all},
+    book::{Block, BookConfig, Volume, format_book},
+    error::BraillifyError,
+    html,
+    xml::{XmlElement, parse_xml},
+};
+
+/// `%20` 같은 URL 인코딩을 푼다.
+fn percent_decode(href: &str) -> String {
+    let bytes = href.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match (bytes[i], href.get(i + 1..i + 3)) {
+            (b'%', Some(hex)) if u8::from_str_radix(hex, 16).is_ok() => {
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// `base` 파일에서 본 상대 경로 `href`를 압축 파일 안의 경로로 바꾼다. `#` 뒤의 조각은 버린다.
+fn resolve(base: &str, href: &str) -> String {
+    let href = percent_decode(href.split('#').next().unwrap_or_default());
+    let mut parts = match base.rsplit_once('/') {
+        Some((dir, _)) => dir.split('/').collect::<Vec<_>>(),
+        None => vec![],
+    };
+    for part in href.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// OPF 매니페스트의 항목
+struct Item {
+    path: String,
+    media_type: String,
+    properties: String,
+}
+
+/// EPUB 3 탐색 문서의 `<nav epub:type="toc">` 목록을 읽는다.
+fn nav_entries(
+    list: &XmlElement,
+    base: &str,
+    level: u8,
+    entries: &mut HashMap<String, (String, u8)>,
+) {
+    for item in list.elements().filter(|e| e.local_name() == "li") {
+        for child in item.elements() {
+            match child.local_name() {
+                "a" | "span" => {
+                    let label = child
+                        .text()
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    if let Some(href) = child.attribute("href")
+                        && !label.is_empty()
+                    {
+                        entries.entry(resolve(base, href)).or_insert((label, level));
+                    }
+                }
+                "ol" => nav_entries(child, base, level.saturating_add(1).min(6), entries),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// EPUB 2 NCX 문서의 `<navPoint>`를 읽는다.
+fn ncx_entries(
+    point: &XmlElement,
+    base: &str,
+    level: u8,
+    entries: &mut HashMap<String, (String, u8)>,
+) {
+    let label = find_all(&point.children, "navLabel")
+        .first()
+        .map(|label| {
+            label
+                .text()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default();
+    if let Some(src) = point
+        .elements()
+        .find(|e| e.local_name() == "content")
+        .and_then(|content| content.attribute("src"))
+        && !label.is_empty()
+    {
+        entries.entry(resolve(base, src)).or_insert((label, level));
+    }
+    for child in point.elements().filter(|e| e.local_name() == "navPoint") {
+        ncx_entries(child, base, level.saturating_add(1).min(6), entries);
+    }
+}
+
+/// 탐색 문서(EPUB 3)나 NCX(EPUB 2)에서 문서마다 첫 차례 항목의 제목과 단계를 찾는다.
+/// 차례 파일이 빠져 있으면 본문만 읽는다.
+fn navigation(
+    archive: &mut Archive,
+    manifest: &HashMap<String, Item>,
+    ncx: Option<&str>,
+) -> Result<HashMap<String, (String, u8)>, BraillifyError> {
+    let mut entries = HashMap::new();
+    if let Some(nav) = manifest
+        .values()
+        .find(|item| item.properties.split_whitespace().any(|p| p == "nav"))
+        && archive.contains(&nav.path)
+    {
+        let nodes = crate::xml::parse_html(&archive.read(&nav.path)?)?;
+        let navs = find_all(&nodes, "nav");
+        let toc = navs
+            .iter()
+            .find(|nav| nav.attribute("type").is_some_and(|t| t.contains("toc")))
+            .or(navs.first());
+        if let Some(list) = toc.and_then(|nav| nav.elements().find(|e| e.local_name() == "ol")) {
+            nav_entries(list, &nav.path, 1, &mut entries);
+        }
+    } else if let Some(ncx) = ncx.and_then(|id| manifest.get(id))
+        && archive.contains(&ncx.path)
+    {
+        let nodes = parse_xml(&archive.read(&ncx.path)?)?;
+        if let Some(map) = find_all(&nodes, "navMap").first() {
+            for point in map.elements().filter(|e| e.local_name() == "navPoint") {
+                ncx_entries(point, &ncx.path, 1, &mut entries);
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// EPUB 파일을 OPF 스파인 순서대로 읽어 점자 책의 덩어리로 나눈다.
+/// 제목이 없는 문서는 차례 항목의 제목을 앞에 넣어 점자 차례에 빠지지 않게 한다.
+pub fn parse_epub(bytes: &[u8]) -> Result<Vec<Block>, BraillifyError> {
+    let mut archive = Archive::new(bytes, "EPUB")?;
+    let container = parse_xml(&archive.read("META-INF/container.xml")?)?;
+    let opf_path = find_all(&container, "rootfile")
+        .first()
+        .and_then(|rootfile| rootfile.attribute("full-path"))
+        .ok_or_else(|| archive.error("rootfile not found"))?
+        .to_string();
+    let opf = parse_xml(&archive.read(&opf_path)?)?;
+
+    let manifest = find_all(&opf, "item")
+        .into_iter()
+        .filter_map(|item| {
+            Some((
+                item.attribute("id")?.to_string(),
+                Item {
+                    path: resolve(&opf_path, item.attribute("href")?),
+                    media_type: item.attribute("media-type").unwrap_or_default().to_string(),
+                    properties: item.attribute("properties").unwrap_or_default().to_string(),
+                },
+            ))
+        })
+        .collect::<HashMap<_, _>>();
+    let spine = find_all(&opf, "spine");
+    let spine = spine
+        .first()
+        .ok_or_else(|| archive.error("spine not found"))?;
+    let navigation = navigation(&mut archive, &manifest, spine.attribute("toc"))?;
+
+    let mut blocks = vec![];
+    for itemref in spine.elements().filter(|e| e.local_name() == "itemref") {
+        let Some(item) = itemref.attribute("idref").and_then(|id| manifest.get(id)) else {
+            continue;
+        };
+        // 탐색 문서는 점자 차례로 대신한다.
+        if item.properties.split_whitespace().any(|p| p == "nav")
+            || !item.media_type.contains("html")
+        {
+            continue;
+        }
+        let mut document = html::parse_html(&archive.read(&item.path)?)?;
+        if !document.iter().any(|b| matches!(b, Block::Heading { .. }))
+            && let Some((label, level)) = navigation.get(&item.path)
+        {
+            document.insert(0, Block::heading(*level, label.clone()));
+        }
+        blocks.extend(document);
+    }
+    Ok(blocks)
+}
+
+/// EPUB 파일을 점자 책으로 배치한다.
+pub fn encode_epub(bytes: &[u8], config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_epub(bytes)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{archive::build_zip, rich::Run};
+
+    const CONTAINER: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>
+</container>"#;
+
+    #[test]
+    pub fn test_resolve() {
+        assert_eq!(
+            resolve("OEBPS/content.opf", "text/ch1.xhtml"),
+            "OEBPS/text/ch1.xhtml"
+        );
+        assert_eq!(
+            resolve("OEBPS/text/nav.xhtml", "../text/ch%201.xhtml#s1"),
+            "OEBPS/text/ch 1.xhtml"
+        );
+        assert_eq!(resolve("content.opf", "ch1.xhtml"), "ch1.xhtml");
+    }
+
+    #[test]
+    pub fn test_parse_epub() {
+        let epub = build_zip(&[
+            ("mimetype", "application/epub+zip"),
+            ("META-INF/container.xml", CONTAINER),
+            (
+                "OEBPS/content.opf",
+                r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+  <manifest>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+    <item id="c1" href="text/c1.xhtml" media-type="application/xhtml+xml"/>
+    <item id="c2" href="text/c2.xhtml" media-type="application/xhtml+xml"/>
+    <item id="css" href="style.css" media-type="text/css"/>
+  </manifest>
+  <spine><itemref idref="nav"/><itemref idref="c2"/><itemref idref="c1"/></spine>
+</package>"#,
+            ),
+            (
+                "OEBPS/nav.xhtml",
+                r#"<?xml version="1.0"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+<body><nav epub:type="toc"><ol>
+  <li><a href="text/c2.xhtml">머리말</a></li>
+  <li><a href="text/c1.xhtml">첫째 장</a></li>
+</ol></nav></body></html>"#,
+            ),
+            (
+                "OEBPS/text/c1.xhtml",
+                r#"<?xml version="1.0"?>
+<html xmlns="http://www.w3.org/1999/xhtml"><body><h1>첫째 장</h1><p>본문<em>강조</em></p></body></html>"#,
+            ),
+            (
+                "OEBPS/text/c2.xhtml",
+                r#"<?xml version="1.0"?>
+<html xmlns="http://www.w3.org/1999/xhtml"><body><p>머리말 글</p></body></html>"#,
+            ),
+        ]);
+        assert_eq!(
+            parse_epub(&epub).unwrap(),
+            vec![
+                Block::heading(1, "머리말"),
+                Block::paragraph("머리말 글"),
+                Block::heading(1, "첫째 장"),
+                Block::Paragraph(vec![
+                    Run::new("본문"),
+                    Run {
+                        emphasis: true,
+                        ..Run::new("강조")
+                    },
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_epub_ncx() {
+        let epub = build_zip(&[
+            ("META-INF/container.xml", CONTAINER),
+            (
+                "OEBPS/content.opf",
+                r#"<package version="2.0"><manifest>
+    <item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
+    <item id="c1" href="c1.html" media-type="application/xhtml+xml"/>
+  </manifest><spine toc="ncx"><itemref idref="c1"/></spine></package>"#,
+            ),
+            (
+                "OEBPS/toc.ncx",
+                r#"<ncx><navMap><navPoint id="p1"><navLabel><text>제1부</text></navLabel>
+    <content src="c1.html"/></navPoint></navMap></ncx>"#,
+            ),
+            ("OEBPS/c1.html", "<p>글"),
+        ]);
+        assert_eq!(
+            parse_epub(&epub).unwrap(),
+            vec![Block::heading(1, "제1부"), Block::paragraph("글")]
+        );
+        assert!(matches!(
+            parse_epub(b"not a zip"),
+            Err(BraillifyError::DocumentError { .. })
+        ));
+    }
+}
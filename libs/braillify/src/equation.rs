@@ -0,0 +1,210 @@
+use crate::{
+    latex::{greek_letter, operator},
+    math::FUNCTIONS,
+    rich::{Run, math_run},
+};
+
+/// 한글 수식 편집기 스크립트의 낱말
+fn tokenize(script: &str) -> Vec<String> {
+    let chars = script.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        i += 1;
+        if c.is_ascii_alphabetic() {
+            while i < chars.len() && chars[i].is_ascii_alphabetic() {
+                i += 1;
+            }
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+        } else if c.is_whitespace() || matches!(c, '~' | '`' | '&' | '#') {
+            // 간격과 정렬 기호는 점자에서 무시한다.
+            continue;
+        }
+        tokens.push(chars[start..i].iter().collect());
+    }
+    tokens
+}
+
+/// 점자로 옮기지 못하는 행렬, 여러 줄 수식 명령어
+const UNSUPPORTED: [&str; 9] = [
+    "matrix", "pmatrix", "bmatrix", "dmatrix", "pile", "lpile", "rpile", "cases", "eqalign",
+];
+
+struct Converter {
+    tokens: Vec<String>,
+    pos: usize,
+    supported: bool,
+}
+
+impl Converter {
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// 닫는 중괄호나 입력 끝까지 읽는다.
+    fn sequence(&mut self) -> String {
+        let mut items: Vec<String> = vec![];
+        while let Some(token) = self.next() {
+            match token.as_str() {
+                "}" => break,
+                "^" | "_" => {
+                    let script = format!("{token}{{{}}}", self.item());
+                    match items.last_mut() {
+                        Some(last) => last.push_str(&script),
+                        None => items.push(script),
+                    }
+                }
+                // `a over b`는 바로 앞의 항을 분자로 삼는다.
+                _ if token.eq_ignore_ascii_case("over") => {
+                    let numerator = items.pop().unwrap_or_default();
+                    let denominator = self.item();
+                    items.push(format!("\\frac{{{numerator}}}{{{denominator}}}"));
+                }
+                _ => {
+                    let atom = self.atom(token);
+                    if !atom.is_empty() {
+                        items.push(atom);
+                    }
+                }
+            }
+        }
+        items.join(" ")
+    }
+
+    /// 첨자, 분모, 근호 안처럼 명령어 뒤에 오는 항 하나를 읽는다.
+    fn item(&mut self) -> String {
+        match self.next() {
+            Some(token) => {
+                let atom = self.atom(token);
+                // 글꼴 명령어처럼 빈 항이면 다음 항을 읽는다.
+                if atom.is_empty() { self.item() } else { atom }
+            }
+            None => String::new(),
+        }
+    }
+
+    fn atom(&mut self, token: String) -> String {
+        if token == "{" {
+            return format!("{{{}}}", self.sequence());
+        }
+        if !token.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return token;
+        }
+        let lower = token.to_ascii_lowercase();
+        match lower.as_str() {
+            "sqrt" => format!("\\sqrt{{{}}}", self.item()),
+            "root" => {
+                let index = self.item();
+                if self
+                    .tokens
+                    .get(self.pos)
+                    .is_some_and(|of| of.eq_ignore_ascii_case("of"))
+                {
+                    self.pos += 1;
+                }
+                format!("\\sqrt[{index}]{{{}}}", self.item())
+            }
+            "left" | "right" | "rm" | "it" | "bold" => String::new(),
+            "inf" => "\\infty".to_string(),
+            _ if UNSUPPORTED.contains(&lower.as_str()) => {
+                self.supported = false;
+                token
+            }
+            _ => {
+                // 대문자로만 쓴 그리스 문자 이름(GAMMA)은 LaTeX의 Gamma로 바꾼다.
+                let greek = if token.chars().all(|c| c.is_ascii_uppercase()) {
+                    lower[..1].to_ascii_uppercase() + &lower[1..]
+                } else {
+                    token.clone()
+                };
+                if greek_letter(&greek).is_some() {
+                    format!("\\{greek}")
+                } else if operator(&lower).is_some() || FUNCTIONS.contains(&lower.as_str()) {
+                    format!("\\{lower}")
+                } else {
+                    token
+                }
+            }
+        }
+    }
+}
+
+/// 한글 수식 편집기 스크립트(`a over b`, `sqrt {x}`)를 LaTeX로 바꾼다.
+/// 행렬처럼 바꿀 수 없는 명령어가 있으면 `None`을 돌려준다.
+pub fn equation_to_latex(script: &str) -> Option<String> {
+    let mut converter = Converter {
+        tokens: tokenize(script),
+        pos: 0,
+        supported: true,
+    };
+    let latex = converter.sequence();
+    converter.supported.then_some(latex)
+}
+
+/// 한글 문서의 수식을 런으로 만든다. 점자로 옮길 수 없는 수식은 스크립트를 점역자 주로 적는다.
+pub fn equation_run(script: &str) -> Run {
+    math_run(&equation_to_latex(script).unwrap_or_default(), script)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_equation_to_latex() {
+        assert_eq!(
+            equation_to_latex("a over b").as_deref(),
+            Some("\\frac{a}{b}")
+        );
+        assert_eq!(
+            equation_to_latex("{a+b} over 2").as_deref(),
+            Some("\\frac{{a + b}}{2}")
+        );
+        assert_eq!(
+            equation_to_latex("x^2 + y_1").as_deref(),
+            Some("x^{2} + y_{1}")
+        );
+        assert_eq!(
+            equation_to_latex("sqrt {x+1}").as_deref(),
+            Some("\\sqrt{{x + 1}}")
+        );
+        assert_eq!(
+            equation_to_latex("root 3 of 8").as_deref(),
+            Some("\\sqrt[3]{8}")
+        );
+        assert_eq!(
+            equation_to_latex("LEFT ( alpha RIGHT ) times GAMMA").as_deref(),
+            Some("( \\alpha ) \\times \\Gamma")
+        );
+        assert_eq!(
+            equation_to_latex("rm sin x ~ LEQ ~ 1").as_deref(),
+            Some("\\sin x \\leq 1")
+        );
+        assert_eq!(equation_to_latex("matrix{a # b}"), None);
+    }
+
+    #[test]
+    pub fn test_equation_run() {
+        assert_eq!(
+            equation_run("1 over 2"),
+            Run {
+                math: true,
+                ..Run::new("\\frac{1}{2}")
+            }
+        );
+        assert_eq!(
+            equation_run("matrix{a # b}"),
+            Run {
+                note: true,
+                ..Run::new("수식 matrix{a # b}")
+            }
+        );
+    }
+}
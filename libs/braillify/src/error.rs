@@ -16,6 +16,9 @@ pub enum BraillifyError {
     InvalidFractionPart { part_name: String, character: char, position: Option<usize> },
     InputTooLong { length: usize, max_length: usize },
     FractionParseError { input: String, error: String },
+    MathParseError { input: String, error: String },
+    XmlParseError { position: usize, error: String },
+    DocumentError { format: String, error: String },
     Other { message: String, context: String },
 }
 
@@ -100,6 +103,15 @@ impl fmt::Display for BraillifyError {
             BraillifyError::FractionParseError { input, error } => {
                 write!(f, "Fraction parse error for '{}': {}", input, error)
             },
+            BraillifyError::MathParseError { input, error } => {
+                write!(f, "Math parse error for '{}': {}", input, error)
+            },
+            BraillifyError::XmlParseError { position, error } => {
+                write!(f, "XML parse error at position {}: {}", position, error)
+            },
+            BraillifyError::DocumentError { format, error } => {
+                write!(f, "{} document error: {}", format, error)
+            },
             BraillifyError::Other { message, context } => {
                 write!(f, "Error: {} (context: {})", message, context)
             },
@@ -109,6 +121,20 @@ impl fmt::Display for BraillifyError {
 
 impl std::error::Error for BraillifyError {}
 
+/// 변환은 했지만 결과를 확인해야 하는 부분에 대한 경고
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodingWarning {
+    /// 경고 대상 원문
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for EncodingWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}': {}", self.text, self.message)
+    }
+}
+
 impl From<String> for BraillifyError {
     fn from(message: String) -> Self {
         BraillifyError::Other {
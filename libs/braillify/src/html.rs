@@ -0,0 +1,551 @@
+use crate::{
+    book::{Block, BookConfig, Volume, format_book},
+    error::BraillifyError,
+    rich::{Language, Run, math_run, push_run, trim_runs},
+    table::{Table, TableCell},
+    xml::{self, XmlElement, XmlNode},
+};
+
+/// 읽지 않는 요소
+const SKIPPED_ELEMENTS: [&str; 7] = [
+    "head", "script", "style", "template", "noscript", "iframe", "object",
+];
+
+/// 숨겨진 요소. `hidden` 속성, `aria-hidden="true"`, `display: none` 스타일을 본다.
+fn is_hidden(element: &XmlElement) -> bool {
+    let style = element
+        .attribute("style")
+        .unwrap_or_default()
+        .replace(char::is_whitespace, "")
+        .to_ascii_lowercase();
+    SKIPPED_ELEMENTS.contains(&element.local_name())
+        || element.attribute("hidden").is_some()
+        || element.attribute("aria-hidden") == Some("true")
+        || style.contains("display:none")
+        || style.contains("visibility:hidden")
+}
+
+/// `lang`, `xml:lang` 속성이 나타내는 언어
+fn language(element: &XmlElement) -> Option<Language> {
+    let lang = element.attribute("lang")?.to_ascii_lowercase();
+    if lang.starts_with("ko") {
+        Some(Language::Korean)
+    } else if lang.starts_with("en") {
+        Some(Language::English)
+    } else {
+        None
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn span_attribute(element: &XmlElement, name: &str) -> usize {
+    element
+        .attribute(name)
+        .and_then(|span| span.trim().parse().ok())
+        .filter(|span| *span > 0)
+        .unwrap_or(1)
+}
+
+/// `<tr>` 요소를 찾는다. 안쪽 표의 행은 제외한다.
+fn table_rows<'a>(element: &'a XmlElement, rows: &mut Vec<(&'a XmlElement, bool)>, head: bool) {
+    for child in element.elements() {
+        match child.local_name() {
+            "tr" => rows.push((child, head)),
+            "thead" => table_rows(child, rows, true),
+            "tbody" | "tfoot" => table_rows(child, rows, false),
+            _ => {}
+        }
+    }
+}
+
+/// 칸 안의 묵자 글. 숨겨진 요소는 건너뛴다.
+fn cell_text(nodes: &[XmlNode], text: &mut String) {
+    for node in nodes {
+        match node {
+            XmlNode::Text(t) => text.push_str(t),
+            XmlNode::Element(element) if is_hidden(element) => {}
+            XmlNode::Element(element) => match element.local_name() {
+                "br" => text.push(' '),
+                _ => cell_text(&element.children, text),
+            },
+        }
+    }
+}
+
+/// 칸 안의 글을 문단과 같은 방법으로 런으로 읽는다.
+/// 그림의 대체 텍스트는 점역자 주로, 수식은 수식으로 적고, 칸 안의 문단과 줄 바꿈은 한 칸 띄어 잇는다.
+fn cell_runs(cell: &XmlElement) -> Vec<Run> {
+    let mut builder = Builder::default();
+    builder.walk(&cell.children, &Run::default());
+    builder.flush();
+    let mut runs = vec![];
+    for block in builder.blocks {
+        let block_runs = match block {
+            Block::Heading { runs, .. }
+            | Block::Paragraph(runs)
+            | Block::ListItem { runs, .. }
+            | Block::Quote(runs) => runs,
+            Block::Code(code) => vec![Run {
+                code: true,
+                ..Run::new(code)
+            }],
+            // 칸 안의 표는 칸마다 한 칸 띄어 잇는다.
+            Block::Table(table) => {
+                let mut cells = vec![];
+                for cell in table.rows.into_iter().flatten() {
+                    if !cells.is_empty() {
+                        cells.push(Run::new(" "));
+                    }
+                    cells.extend(cell.runs);
+                }
+                cells
+            }
+            Block::PrintPage(_) => continue,
+        };
+        if !runs.is_empty() {
+            push_run(&mut runs, Run::new(" "));
+        }
+        for run in block_runs {
+            push_run(
+                &mut runs,
+                Run {
+                    text: run.text.replace('\n', " "),
+                    ..run
+                },
+            );
+        }
+    }
+    runs
+}
+
+/// `<table>`을 표로 읽는다. `<thead>` 안의 행이나 모두 `<th>`인 앞쪽 행을 제목 행으로 본다.
+fn parse_table(element: &XmlElement) -> Table {
+    let mut rows = vec![];
+    table_rows(element, &mut rows, false);
+    let mut table = Table::default();
+    let mut in_header = true;
+    for (row, head) in rows {
+        let cells = row
+            .elements()
+            .filter(|cell| matches!(cell.local_name(), "td" | "th") && !is_hidden(cell))
+            .collect::<Vec<_>>();
+        if in_header
+            && (head || (!cells.is_empty() && cells.iter().all(|c| c.local_name() == "th")))
+        {
+            table.header_rows += 1;
+        } else {
+            in_header = false;
+        }
+        table.rows.push(
+            cells
+                .into_iter()
+                .map(|cell| TableCell {
+                    text: {
+                        let mut text = String::new();
+                        cell_text(&cell.children, &mut text);
+                        collapse_whitespace(&text)
+                    },
+                    runs: cell_runs(cell),
+                    row_span: span_attribute(cell, "rowspan"),
+                    col_span: span_attribute(cell, "colspan"),
+                })
+                .collect(),
+        );
+    }
+    table
+}
+
+/// HTML 요소를 읽는 순서대로 `Block`으로 모은다.
+#[derive(Default)]
+struct Builder {
+    blocks: Vec<Block>,
+    runs: Vec<Run>,
+    heading: Option<u8>,
+    quote: usize,
+    /// 목록마다 다음 항목의 번호. 번호 없는 목록은 `None`이다.
+    lists: Vec<Option<u64>>,
+    /// 항목 첫 글 앞에 적을 번호
+    item_number: Option<String>,
+}
+
+impl Builder {
+    fn push_run(&mut self, run: Run) {
+        if let Some(number) = self.item_number.take() {
+            self.runs.push(Run::new(number));
+        }
+        push_run(&mut self.runs, run);
+    }
+
+    /// 연속된 공백은 한 칸으로 줄이고, 덩어리 첫머리의 공백은 버린다.
+    fn push_text(&mut self, text: &str, style: &Run) {
+        let mut collapsed = collapse_whitespace(text);
+        if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+            collapsed.push(' ');
+        }
+        let after_space = self
+            .runs
+            .last()
+            .is_none_or(|last| last.text.ends_with([' ', '\n']));
+        if text.starts_with(char::is_whitespace) && !after_space {
+            collapsed.insert(0, ' ');
+        }
+        if collapsed.is_empty() {
+            return;
+        }
+        self.push_run(Run {
+            text: collapsed,
+            ..style.clone()
+        });
+    }
+
+    /// 모은 글을 제목, 항목, 인용문, 문단 가운데 알맞은 덩어리로 내보낸다.
+    fn flush(&mut self) {
+        let runs = trim_runs(std::mem::take(&mut self.runs));
+        if runs.is_empty() {
+            return;
+        }
+        let block = if let Some(level) = self.heading {
+            Block::Heading { level, runs }
+        } else if !self.lists.is_empty() {
+            Block::ListItem {
+                depth: self.lists.len() - 1,
+                runs,
+            }
+        } else if self.quote > 0 {
+            Block::Quote(runs)
+        } else {
+            Block::Paragraph(runs)
+        };
+        self.blocks.push(block);
+    }
+
+    fn walk(&mut self, nodes: &[XmlNode], style: &Run) {
+        for node in nodes {
+            match node {
+                XmlNode::Element(element) => self.element(element, style),
+                XmlNode::Text(text) => self.push_text(text, style),
+            }
+        }
+    }
+
+    fn element(&mut self, element: &XmlElement, style: &Run) {
+        if is_hidden(element) {
+            return;
+        }
+        let mut style = style.clone();
+        if let Some(language) = language(element) {
+            style.language = Some(language);
+        }
+        let name = element.local_name();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading = name[1..].parse().ok();
+                self.walk(&element.children, &style);
+                self.flush();
+                self.heading = None;
+            }
+            "blockquote" => {
+                self.flush();
+                self.quote += 1;
+                self.walk(&element.children, &style);
+                self.flush();
+                self.quote -= 1;
+            }
+            "ul" | "ol" | "menu" => {
+                self.flush();
+                let start = element
+                    .attribute("start")
+                    .and_then(|start| start.trim().parse().ok())
+                    .unwrap_or(1);
+                self.lists.push((name == "ol").then_some(start));
+                self.walk(&element.children, &style);
+                self.flush();
+                self.lists.pop();
+            }
+            "li" => {
+                self.flush();
+                self.item_number = self
+                    .lists
+                    .last_mut()
+                    .and_then(Option::as_mut)
+                    .map(|number| {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    });
+                self.walk(&element.children, &style);
+                self.flush();
+            }
+            "pre" => {
+                self.flush();
+                let code = element.text();
+                let code = code.strip_prefix('\n').unwrap_or(&code).trim_end();
+                if !code.is_empty() {
+                    self.blocks.push(Block::Code(code.to_string()));
+                }
+            }
+            "table" => {
+                self.flush();
+                // 표 제목은 표 앞에 문단으로 적는다.
+                for caption in element.elements().filter(|e| e.local_name() == "caption") {
+                    self.element(caption, &style);
+                }
+                self.blocks.push(Block::Table(parse_table(element)));
+            }
+            "br" => self.push_run(Run {
+                text: "\n".to_string(),
+                ..style
+            }),
+            "hr" => self.flush(),
+            // 그림의 대체 텍스트는 점역자 주로 적는다. 꾸밈용 그림(alt="")은 건너뛴다.
+            "img" => {
+                if let Some(alt) = element
+                    .attribute("alt")
+                    .map(collapse_whitespace)
+                    .filter(|alt| !alt.is_empty())
+                {
+                    self.push_run(Run {
+                        text: alt,
+                        note: true,
+                        ..style
+                    });
+                }
+            }
+            "math" => {
+                let block = element.attribute("display") == Some("block");
+                if block {
+                    self.flush();
+                }
+                let run = math_run(&element.to_xml(), &element.text());
+                self.push_run(Run {
+                    text: run.text,
+                    math: run.math,
+                    note: run.note,
+                    ..style
+                });
+                if block {
+                    self.flush();
+                }
+            }
+            "p" | "div" | "section" | "article" | "main" | "header" | "footer" | "nav"
+            | "aside" | "figure" | "figcaption" | "address" | "dl" | "dt" | "dd" | "body"
+            | "html" | "caption" | "details" | "summary" => {
+                self.flush();
+                self.walk(&element.children, &style);
+                self.flush();
+            }
+            _ => {
+                match name {
+                    "em" => style.emphasis = true,
+                    "strong" | "b" => style.bold = true,
+                    "i" | "cite" | "dfn" => style.italic = true,
+                    "u" | "ins" => style.underline = true,
+                    "s" | "del" | "strike" => style.strike = true,
+                    "code" | "kbd" | "samp" | "tt" => style.code = true,
+                    _ => {}
+                }
+                self.walk(&element.children, &style);
+            }
+        }
+    }
+}
+
+/// HTML이나 XHTML 문서를 읽는 순서대로 점자 책의 덩어리로 나눈다.
+/// `lang` 속성에 따라 로마자표를 쓸지 정하고, 숨겨진 요소는 건너뛴다.
+pub fn parse_html(input: &str) -> Result<Vec<Block>, BraillifyError> {
+    let mut builder = Builder::default();
+    builder.walk(&xml::parse_html(input)?, &Run::default());
+    builder.flush();
+    Ok(builder.blocks)
+}
+
+/// HTML 문서를 점자 책으로 배치한다.
+pub fn encode_html(input: &str, config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_html(input)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_html() {
+        let blocks = parse_html(
+            "<html lang=\"ko\"><head><title>무시</title></head><body>\
+             <h2>제목</h2>\
+             <p>서울은 <em>대한민국</em>의\n   <strong>수도</strong>이다.</p>\
+             <p hidden>숨김</p><div aria-hidden=\"true\">숨김</div>\
+             <p style=\"display: none\">숨김</p>\
+             <ol><li>사과<li>배<ul><li>감</ul></ol>\
+             <blockquote><p>인용</p></blockquote>\
+             </body></html>",
+        )
+        .unwrap();
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading {
+                    level: 2,
+                    runs: vec![Run {
+                        language: Some(Language::Korean),
+                        ..Run::new("제목")
+                    }]
+                },
+                Block::Paragraph(vec![
+                    Run {
+                        language: Some(Language::Korean),
+                        ..Run::new("서울은 ")
+                    },
+                    Run {
+                        emphasis: true,
+                        language: Some(Language::Korean),
+                        ..Run::new("대한민국")
+                    },
+                    Run {
+                        language: Some(Language::Korean),
+                        ..Run::new("의 ")
+                    },
+                    Run {
+                        bold: true,
+                        language: Some(Language::Korean),
+                        ..Run::new("수도")
+                    },
+                    Run {
+                        language: Some(Language::Korean),
+                        ..Run::new("이다.")
+                    },
+                ]),
+                Block::ListItem {
+                    depth: 0,
+                    runs: vec![
+                        Run::new("1. "),
+                        Run {
+                            language: Some(Language::Korean),
+                            ..Run::new("사과")
+                        }
+                    ]
+                },
+                Block::ListItem {
+                    depth: 0,
+                    runs: vec![
+                        Run::new("2. "),
+                        Run {
+                            language: Some(Language::Korean),
+                            ..Run::new("배")
+                        }
+                    ]
+                },
+                Block::ListItem {
+                    depth: 1,
+                    runs: vec![Run {
+                        language: Some(Language::Korean),
+                        ..Run::new("감")
+                    }]
+                },
+                Block::Quote(vec![Run {
+                    language: Some(Language::Korean),
+                    ..Run::new("인용")
+                }]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_html_inline() {
+        let blocks = parse_html(
+            "<p>그림 <img src=\"a.png\" alt=\"고양이 사진\"> <img src=\"b.png\" alt=\"\">\
+             <span lang=\"en\">Seoul</span> <math><mi>x</mi></math></p>\
+             <table><caption>과일 <b>값</b></caption>\
+             <tr><th>과일<th>값<tr><td>사과<span aria-hidden=\"true\">숨김</span> \
+             <img src=\"c.png\" alt=\"빨강\"><td colspan=\"2\"><math><mn>1000</mn></math></table>",
+        )
+        .unwrap();
+        let cell = |text: &str| TableCell {
+            runs: vec![Run::new(text)],
+            ..TableCell::new(text)
+        };
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Paragraph(vec![
+                    Run::new("그림 "),
+                    Run {
+                        note: true,
+                        ..Run::new("고양이 사진")
+                    },
+                    Run::new(" "),
+                    Run {
+                        language: Some(Language::English),
+                        ..Run::new("Seoul")
+                    },
+                    Run::new(" "),
+                    Run {
+                        math: true,
+                        ..Run::new("<math><mi>x</mi></math>")
+                    },
+                ]),
+                Block::Paragraph(vec![
+                    Run::new("과일 "),
+                    Run {
+                        bold: true,
+                        ..Run::new("값")
+                    },
+                ]),
+                Block::Table(Table {
+                    rows: vec![
+                        vec![cell("과일"), cell("값")],
+                        vec![
+                            // 칸 안의 그림도 대체 텍스트를 점역자 주로 적고, 수식은 수식으로 적는다.
+                            TableCell {
+                                runs: vec![
+                                    Run::new("사과 "),
+                                    Run {
+                                        note: true,
+                                        ..Run::new("빨강")
+                                    }
+                                ],
+                                ..TableCell::new("사과")
+                            },
+                            TableCell {
+                                runs: vec![Run {
+                                    math: true,
+                                    ..Run::new("<math><mn>1000</mn></math>")
+                                }],
+                                col_span: 2,
+                                ..TableCell::new("1000")
+                            }
+                        ],
+                    ],
+                    header_rows: 1,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_encode_html() {
+        let config = BookConfig {
+            page_numbers: false,
+            table_of_contents: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            encode_html("<p>서울은 <em>수도</em>이다.</p>", &config).unwrap(),
+            format_book(
+                &[Block::Paragraph(vec![
+                    Run::new("서울은 "),
+                    Run {
+                        emphasis: true,
+                        ..Run::new("수도")
+                    },
+                    Run::new("이다."),
+                ])],
+                &config
+            )
+            .unwrap()
+        );
+    }
+}
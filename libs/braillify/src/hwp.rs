@@ -0,0 +1,679 @@
+use std::io::{Cursor, Read};
+
+use cfb::CompoundFile;
+use flate2::read::DeflateDecoder;
+
+use crate::{
+    book::{Block, BookConfig, Volume, format_book},
+    equation::equation_run,
+    error::BraillifyError,
+    rich::{Run, note_paragraph, push_text, trim_runs},
+    table::{Table, TableCell},
+};
+
+const HWPTAG_BEGIN: u16 = 0x10;
+const HWPTAG_CHAR_SHAPE: u16 = HWPTAG_BEGIN + 5;
+const HWPTAG_STYLE: u16 = HWPTAG_BEGIN + 10;
+const HWPTAG_PARA_HEADER: u16 = HWPTAG_BEGIN + 50;
+const HWPTAG_PARA_TEXT: u16 = HWPTAG_BEGIN + 51;
+const HWPTAG_PARA_CHAR_SHAPE: u16 = HWPTAG_BEGIN + 52;
+const HWPTAG_CTRL_HEADER: u16 = HWPTAG_BEGIN + 55;
+const HWPTAG_LIST_HEADER: u16 = HWPTAG_BEGIN + 56;
+const HWPTAG_TABLE: u16 = HWPTAG_BEGIN + 61;
+const HWPTAG_EQEDIT: u16 = HWPTAG_BEGIN + 72;
+
+/// 본문에 글을 남기지 않는 컨트롤. 구역·단 정의, 머리말·꼬리말, 쪽 번호, 책갈피 등이다.
+const IGNORED_CONTROLS: [&str; 12] = [
+    "secd", "cold", "head", "foot", "atno", "nwno", "pgnp", "pghd", "pgct", "bokm", "idxm", "tcmt",
+];
+
+fn hwp_error(error: impl ToString) -> BraillifyError {
+    BraillifyError::DocumentError {
+        format: "HWP".to_string(),
+        error: error.to_string(),
+    }
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// 길이(WORD)가 앞에 붙은 UTF-16 문자열
+fn wide_string(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    let len = u16_at(data, offset)? as usize;
+    let units = data
+        .get(offset + 2..offset + 2 + len * 2)?
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect::<Vec<_>>();
+    Some((String::from_utf16_lossy(&units), offset + 2 + len * 2))
+}
+
+/// 레코드와 그 아래 단계의 레코드
+struct Record {
+    tag: u16,
+    data: Vec<u8>,
+    children: Vec<Record>,
+}
+
+impl Record {
+    fn child(&self, tag: u16) -> Option<&Record> {
+        self.children.iter().find(|child| child.tag == tag)
+    }
+}
+
+/// 레코드 헤더(태그 10비트, 단계 10비트, 크기 12비트)를 따라 스트림을 레코드의 나무로 읽는다.
+fn parse_records(data: &[u8]) -> Result<Vec<Record>, BraillifyError> {
+    let mut flat: Vec<(u32, Record)> = vec![];
+    let mut pos = 0;
+    while pos + 4 <= data.len() {
+        let header = u32_at(data, pos).unwrap_or_default();
+        pos += 4;
+        let mut size = (header >> 20) as usize;
+        if size == 0xfff {
+            size = u32_at(data, pos).ok_or_else(|| hwp_error("truncated record"))? as usize;
+            pos += 4;
+        }
+        let body = data
+            .get(pos..pos + size)
+            .ok_or_else(|| hwp_error("truncated record"))?;
+        pos += size;
+        flat.push((
+            (header >> 10) & 0x3ff,
+            Record {
+                tag: (header & 0x3ff) as u16,
+                data: body.to_vec(),
+                children: vec![],
+            },
+        ));
+    }
+    // 단계가 더 깊은 레코드를 바로 앞의 얕은 레코드 아래에 넣는다.
+    let mut stack: Vec<(u32, Record)> = vec![];
+    let mut roots = vec![];
+    for (level, record) in flat {
+        while let Some((top, _)) = stack.last()
+            && *top >= level
+        {
+            let (_, done) = stack.pop().unwrap();
+            match stack.last_mut() {
+                Some((_, parent)) => parent.children.push(done),
+                None => roots.push(done),
+            }
+        }
+        stack.push((level, record));
+    }
+    while let Some((_, done)) = stack.pop() {
+        match stack.last_mut() {
+            Some((_, parent)) => parent.children.push(done),
+            None => roots.push(done),
+        }
+    }
+    Ok(roots)
+}
+
+/// 문서 정보의 글자 모양과 스타일
+#[derive(Default)]
+struct DocInfo {
+    char_shapes: Vec<Run>,
+    /// 스타일마다 개요 단계. 개요 스타일이 아니면 `None`이다.
+    heading_styles: Vec<Option<u8>>,
+}
+
+impl DocInfo {
+    fn parse(records: &[Record]) -> Self {
+        let mut info = DocInfo::default();
+        for record in records {
+            match record.tag {
+                // 글꼴, 장평, 자간 등 42바이트와 기준 크기 뒤의 속성: 기울임(0), 진하게(1), 밑줄(2~3), 취소선(18~20)
+                HWPTAG_CHAR_SHAPE => {
+                    let property = u32_at(&record.data, 46).unwrap_or_default();
+                    info.char_shapes.push(Run {
+                        italic: property & 1 != 0,
+                        bold: property & 2 != 0,
+                        underline: (property >> 2) & 3 != 0,
+                        strike: (property >> 18) & 7 != 0,
+                        ..Default::default()
+                    });
+                }
+                HWPTAG_STYLE => {
+                    let level = wide_string(&record.data, 0).and_then(|(name, next)| {
+                        let english = wide_string(&record.data, next)
+                            .map(|(english, _)| english)
+                            .unwrap_or_default();
+                        let level = name
+                            .strip_prefix("개요 ")
+                            .or(english.strip_prefix("Outline "))?;
+                        level.trim().parse::<u8>().ok()
+                    });
+                    info.heading_styles
+                        .push(level.map(|level| level.clamp(1, 6)));
+                }
+                _ => {}
+            }
+        }
+        info
+    }
+}
+
+/// 본문 레코드를 `Block`으로 모은다.
+struct Builder<'a> {
+    info: &'a DocInfo,
+    /// 지금까지 매긴 각주와 미주 번호
+    note_count: usize,
+}
+
+impl Builder<'_> {
+    fn push_block(blocks: &mut Vec<Block>, runs: &mut Vec<Run>, level: Option<u8>) {
+        let runs = trim_runs(std::mem::take(runs));
+        if runs.is_empty() {
+            return;
+        }
+        blocks.push(match level {
+            Some(level) => Block::Heading { level, runs },
+            None => Block::Paragraph(runs),
+        });
+    }
+
+    /// 문단 헤더 아래의 문단 글과 글자 모양, 컨트롤을 읽는다.
+    fn paragraph(&mut self, paragraph: &Record, blocks: &mut Vec<Block>) {
+        let level = paragraph
+            .data
+            .get(10)
+            .and_then(|style| self.info.heading_styles.get(*style as usize).copied())
+            .flatten();
+        let units = paragraph
+            .child(HWPTAG_PARA_TEXT)
+            .map(|text| {
+                text.data
+                    .chunks_exact(2)
+                    .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        // (시작 위치, 글자 모양) 쌍
+        let shapes = paragraph
+            .child(HWPTAG_PARA_CHAR_SHAPE)
+            .map(|shape| {
+                shape
+                    .data
+                    .chunks_exact(8)
+                    .map(|pair| (u32_at(pair, 0).unwrap(), u32_at(pair, 4).unwrap()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let mut controls = paragraph
+            .children
+            .iter()
+            .filter(|child| child.tag == HWPTAG_CTRL_HEADER);
+
+        let mut runs = vec![];
+        let mut notes = vec![];
+        let mut pos = 0;
+        while pos < units.len() {
+            let style = shapes
+                .iter()
+                .rfind(|(start, _)| *start as usize <= pos)
+                .and_then(|(_, id)| self.info.char_shapes.get(*id as usize))
+                .cloned()
+                .unwrap_or_default();
+            let unit = units[pos];
+            match unit {
+                // 확장 컨트롤: 표, 수식, 그리기 개체 등. 여덟 글자를 차지하고 컨트롤 헤더와 짝을 이룬다.
+                1..=3 | 11 | 12 | 14..=18 | 21..=23 => {
+                    if let Some(control) = controls.next() {
+                        self.control(control, blocks, &mut runs, &mut notes, level, &style);
+                    }
+                    pos += 8;
+                }
+                // 인라인 컨트롤: 여덟 글자를 차지한다. 9는 탭이다.
+                4..=9 | 19 | 20 => {
+                    if unit == 9 {
+                        push_text(&mut runs, " ", &style);
+                    }
+                    pos += 8;
+                }
+                10 => {
+                    push_text(&mut runs, "\n", &style);
+                    pos += 1;
+                }
+                24 => {
+                    push_text(&mut runs, "-", &style);
+                    pos += 1;
+                }
+                30 | 31 => {
+                    push_text(&mut runs, " ", &style);
+                    pos += 1;
+                }
+                0..=31 => pos += 1,
+                _ => {
+                    let end = if (0xd800..0xdc00).contains(&unit) {
+                        pos + 2
+                    } else {
+                        pos + 1
+                    };
+                    let text = String::from_utf16_lossy(&units[pos..end.min(units.len())]);
+                    push_text(&mut runs, &text, &style);
+                    pos = end;
+                }
+            }
+        }
+        Self::push_block(blocks, &mut runs, level);
+        for (number, runs) in notes {
+            blocks.push(note_paragraph(number, runs));
+        }
+    }
+
+    /// 컨트롤 아래 문단 리스트의 문단들
+    fn sub_list(&mut self, control: &Record) -> Vec<Block> {
+        let mut blocks = vec![];
+        for paragraph in control
+            .children
+            .iter()
+            .filter(|child| child.tag == HWPTAG_PARA_HEADER)
+        {
+            self.paragraph(paragraph, &mut blocks);
+        }
+        blocks
+    }
+
+    fn control(
+        &mut self,
+        control: &Record,
+        blocks: &mut Vec<Block>,
+        runs: &mut Vec<Run>,
+        notes: &mut Vec<(usize, Vec<Run>)>,
+        level: Option<u8>,
+        style: &Run,
+    ) {
+        let id = u32_at(&control.data, 0).unwrap_or_default().to_be_bytes();
+        let id = String::from_utf8_lossy(&id);
+        match id.as_ref() {
+            "tbl " => {
+                Self::push_block(blocks, runs, level);
+                blocks.push(Block::Table(self.table(control)));
+            }
+            "eqed" => {
+                let script = control
+                    .child(HWPTAG_EQEDIT)
+                    .and_then(|eq| wide_string(&eq.data, 4))
+                    .map(|(script, _)| script)
+                    .unwrap_or_default();
+                runs.push(equation_run(&script));
+            }
+            // 각주와 미주는 본문에 번호를 적고, 내용은 문단 뒤에 적는다.
+            "fn  " | "en  " => {
+                self.note_count += 1;
+                let number = self.note_count;
+                push_text(runs, &format!("{number})"), &Run::default());
+                let mut note = vec![];
+                for block in self.sub_list(control) {
+                    if let Block::Paragraph(runs) | Block::Heading { runs, .. } = block {
+                        if !note.is_empty() {
+                            push_text(&mut note, " ", &Run::default());
+                        }
+                        note.extend(runs);
+                    }
+                }
+                notes.push((number, note));
+            }
+            id if IGNORED_CONTROLS.contains(&id) => {}
+            // 옮길 수 없는 개체는 빠뜨린 것을 알 수 있게 점역자 주로 적는다.
+            id => {
+                let note = if id == "gso " {
+                    "그림 생략"
+                } else {
+                    "개체 생략"
+                };
+                runs.push(Run {
+                    note: true,
+                    text: note.to_string(),
+                    ..style.clone()
+                });
+            }
+        }
+    }
+
+    /// 표 컨트롤 아래의 칸(리스트 헤더)마다 뒤따르는 문단의 글을 모은다.
+    /// 칸 안의 표는 칸마다 글을 이어 적는다.
+    fn table(&mut self, control: &Record) -> Table {
+        let repeat_header = control
+            .child(HWPTAG_TABLE)
+            .and_then(|table| u32_at(&table.data, 0))
+            .is_some_and(|property| property & 4 != 0);
+        // (행, 열, 칸)
+        let mut cells: Vec<(u16, u16, TableCell)> = vec![];
+        for child in &control.children {
+            match child.tag {
+                // 문단 수(2), 알 수 없음(2), 속성(4) 뒤에 열 주소, 행 주소, 열 병합, 행 병합
+                HWPTAG_LIST_HEADER => {
+                    let field = |offset: usize| u16_at(&child.data, 8 + offset).unwrap_or_default();
+                    cells.push((
+                        field(2),
+                        field(0),
+                        TableCell {
+                            row_span: field(6).max(1) as usize,
+                            col_span: field(4).max(1) as usize,
+                            ..TableCell::new(String::new())
+                        },
+                    ));
+                }
+                HWPTAG_PARA_HEADER => {
+                    if let Some((_, _, cell)) = cells.last_mut() {
+                        let mut paragraphs = vec![];
+                        self.paragraph(child, &mut paragraphs);
+                        let texts = paragraphs.into_iter().flat_map(|block| match block {
+                            Block::Paragraph(runs) | Block::Heading { runs, .. } => {
+                                vec![runs.into_iter().map(|run| run.text).collect::<String>()]
+                            }
+                            Block::Table(table) => table
+                                .rows
+                                .into_iter()
+                                .flatten()
+                                .map(|cell| cell.text)
+                                .collect(),
+                            _ => vec![],
+                        });
+                        for text in texts.filter(|text| !text.is_empty()) {
+                            if !cell.text.is_empty() {
+                                cell.text.push(' ');
+                            }
+                            cell.text.push_str(&text);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        cells.sort_by_key(|(row, column, _)| (*row, *column));
+        let mut table = Table {
+            rows: vec![],
+            header_rows: repeat_header as usize,
+        };
+        for (row, _, cell) in cells {
+            while table.rows.len() <= row as usize {
+                table.rows.push(vec![]);
+            }
+            table.rows[row as usize].push(cell);
+        }
+        table
+    }
+}
+
+fn read_stream(
+    file: &mut CompoundFile<Cursor<&[u8]>>,
+    path: &str,
+    compressed: bool,
+) -> Result<Vec<u8>, BraillifyError> {
+    let mut data = vec![];
+    file.open_stream(path)
+        .and_then(|mut stream| stream.read_to_end(&mut data))
+        .map_err(|e| hwp_error(format!("{path}: {e}")))?;
+    if !compressed {
+        return Ok(data);
+    }
+    let mut inflated = vec![];
+    DeflateDecoder::new(data.as_slice())
+        .read_to_end(&mut inflated)
+        .map_err(|e| hwp_error(format!("{path}: {e}")))?;
+    Ok(inflated)
+}
+
+/// 한글 5.0 문서(.hwp)를 구역 순서대로 읽어 점자 책의 덩어리로 나눈다.
+/// 표와 수식은 구조를 살려 적고, 각주와 미주는 번호가 나온 문단 뒤에 적는다.
+/// 그림처럼 옮길 수 없는 개체는 점역자 주로 알린다.
+/// 암호가 걸렸거나 배포용으로 저장한 문서는 읽지 못한다.
+pub fn parse_hwp(bytes: &[u8]) -> Result<Vec<Block>, BraillifyError> {
+    let mut file = CompoundFile::open(Cursor::new(bytes)).map_err(hwp_error)?;
+    let header = read_stream(&mut file, "/FileHeader", false)?;
+    if !header.starts_with(b"HWP Document File") {
+        return Err(hwp_error("not an HWP 5.0 document"));
+    }
+    let property = u32_at(&header, 36).unwrap_or_default();
+    if property & 2 != 0 {
+        return Err(hwp_error("password-protected documents are not supported"));
+    }
+    if property & 4 != 0 {
+        return Err(hwp_error("distribution documents are not supported"));
+    }
+    let compressed = property & 1 != 0;
+
+    let info = DocInfo::parse(&parse_records(&read_stream(
+        &mut file, "/DocInfo", compressed,
+    )?)?);
+    let mut builder = Builder {
+        info: &info,
+        note_count: 0,
+    };
+    let mut blocks = vec![];
+    let mut section = 0;
+    while file.is_stream(format!("/BodyText/Section{section}")) {
+        let records = parse_records(&read_stream(
+            &mut file,
+            &format!("/BodyText/Section{section}"),
+            compressed,
+        )?)?;
+        for paragraph in records.iter().filter(|r| r.tag == HWPTAG_PARA_HEADER) {
+            builder.paragraph(paragraph, &mut blocks);
+        }
+        section += 1;
+    }
+    if section == 0 {
+        return Err(hwp_error("section not found"));
+    }
+    Ok(blocks)
+}
+
+/// 한글 5.0 문서를 점자 책으로 배치한다.
+pub fn encode_hwp(bytes: &[u8], config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_hwp(bytes)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use flate2::{Compression, write::DeflateEncoder};
+
+    use super::*;
+
+    fn record(tag: u16, level: u32, data: &[u8]) -> Vec<u8> {
+        let header = tag as u32 | (level << 10) | ((data.len() as u32) << 20);
+        [header.to_le_bytes().as_slice(), data].concat()
+    }
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn wide(text: &str) -> Vec<u8> {
+        [
+            (text.encode_utf16().count() as u16).to_le_bytes().to_vec(),
+            utf16(text),
+        ]
+        .concat()
+    }
+
+    /// 확장 컨트롤 문자. 컨트롤 아이디를 거꾸로 적는다.
+    fn extended(code: u16, id: &str) -> Vec<u8> {
+        let mut units = vec![code];
+        let id = u32::from_be_bytes(id.as_bytes().try_into().unwrap());
+        units.extend([id as u16, (id >> 16) as u16, 0, 0, 0, 0, code]);
+        units.into_iter().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn paragraph(level: u32, style: u8, text: &[u8], shapes: &[(u32, u32)]) -> Vec<u8> {
+        let mut header = vec![0; 22];
+        header[10] = style;
+        let shapes = shapes
+            .iter()
+            .flat_map(|(pos, id)| [pos.to_le_bytes(), id.to_le_bytes()].concat())
+            .collect::<Vec<_>>();
+        [
+            record(HWPTAG_PARA_HEADER, level, &header),
+            record(HWPTAG_PARA_TEXT, level + 1, text),
+            record(HWPTAG_PARA_CHAR_SHAPE, level + 1, &shapes),
+        ]
+        .concat()
+    }
+
+    fn char_shape(property: u32) -> Vec<u8> {
+        let mut data = vec![0; 50];
+        data[46..50].copy_from_slice(&property.to_le_bytes());
+        record(HWPTAG_CHAR_SHAPE, 1, &data)
+    }
+
+    fn list_header(level: u32, row: u16, column: u16, col_span: u16) -> Vec<u8> {
+        let mut header = vec![0; 8];
+        for value in [column, row, col_span, 1] {
+            header.extend(value.to_le_bytes());
+        }
+        record(HWPTAG_LIST_HEADER, level, &header)
+    }
+
+    fn cell(level: u32, row: u16, column: u16, col_span: u16, text: &str) -> Vec<u8> {
+        [
+            list_header(level, row, column, col_span),
+            paragraph(level, 0, &utf16(&format!("{text}\r")), &[(0, 0)]),
+        ]
+        .concat()
+    }
+
+    fn build_hwp() -> Vec<u8> {
+        let doc_info = [
+            char_shape(0),
+            char_shape(2),
+            record(HWPTAG_STYLE, 1, &[wide("바탕글"), wide("Normal")].concat()),
+            record(
+                HWPTAG_STYLE,
+                1,
+                &[wide("개요 1"), wide("Outline 1")].concat(),
+            ),
+        ]
+        .concat();
+        let mut table = vec![0; 8];
+        table[0] = 4;
+        let mut eqedit = vec![0; 4];
+        eqedit.extend(wide("1 over 2"));
+        let section = [
+            paragraph(0, 1, &utf16("제목\r"), &[(0, 0)]),
+            paragraph(
+                0,
+                0,
+                &[utf16("서울은 수도"), extended(17, "fn  "), utf16("\r")].concat(),
+                &[(0, 0), (4, 1), (6, 0)],
+            ),
+            record(HWPTAG_CTRL_HEADER, 1, b"  nf"),
+            list_header(2, 0, 0, 1),
+            paragraph(2, 0, &utf16("대한민국의\r"), &[(0, 0)]),
+            paragraph(2, 0, &utf16("수도\r"), &[(0, 0)]),
+            paragraph(
+                0,
+                0,
+                &[
+                    utf16("값은 "),
+                    extended(11, "eqed"),
+                    extended(11, "gso "),
+                    extended(11, "tbl "),
+                    utf16("\r"),
+                ]
+                .concat(),
+                &[(0, 0)],
+            ),
+            record(HWPTAG_CTRL_HEADER, 1, b"deqe"),
+            record(HWPTAG_EQEDIT, 2, &eqedit),
+            record(HWPTAG_CTRL_HEADER, 1, b" osg"),
+            record(HWPTAG_CTRL_HEADER, 1, b" lbt"),
+            record(HWPTAG_TABLE, 2, &table),
+            cell(2, 0, 0, 1, "과일"),
+            cell(2, 0, 1, 1, "값"),
+            list_header(2, 1, 0, 2),
+            paragraph(
+                2,
+                0,
+                &[utf16("사과"), extended(11, "tbl "), utf16("\r")].concat(),
+                &[(0, 0)],
+            ),
+            record(HWPTAG_CTRL_HEADER, 3, b" lbt"),
+            record(HWPTAG_TABLE, 4, &[0; 8]),
+            cell(4, 0, 0, 1, "빨강"),
+            cell(4, 0, 1, 1, "초록"),
+        ]
+        .concat();
+
+        let compress = |data: &[u8]| {
+            let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        };
+        let mut header = b"HWP Document File".to_vec();
+        header.resize(256, 0);
+        header[36] = 1;
+        let mut file = CompoundFile::create(Cursor::new(vec![])).unwrap();
+        file.create_storage("/BodyText").unwrap();
+        for (path, data) in [
+            ("/FileHeader", header),
+            ("/DocInfo", compress(&doc_info)),
+            ("/BodyText/Section0", compress(&section)),
+        ] {
+            file.create_stream(path).unwrap().write_all(&data).unwrap();
+        }
+        file.flush().unwrap();
+        file.into_inner().into_inner()
+    }
+
+    #[test]
+    pub fn test_parse_hwp() {
+        assert_eq!(
+            parse_hwp(&build_hwp()).unwrap(),
+            vec![
+                Block::heading(1, "제목"),
+                Block::Paragraph(vec![
+                    Run::new("서울은 "),
+                    Run {
+                        bold: true,
+                        ..Run::new("수도")
+                    },
+                    Run::new("1)"),
+                ]),
+                Block::Paragraph(vec![Run::new("1) 대한민국의 수도")]),
+                Block::Paragraph(vec![
+                    Run::new("값은 "),
+                    Run {
+                        math: true,
+                        ..Run::new("\\frac{1}{2}")
+                    },
+                    Run {
+                        note: true,
+                        ..Run::new("그림 생략")
+                    },
+                ]),
+                Block::Table(Table {
+                    rows: vec![
+                        vec![TableCell::new("과일"), TableCell::new("값")],
+                        vec![TableCell {
+                            col_span: 2,
+                            ..TableCell::new("사과 빨강 초록")
+                        }],
+                    ],
+                    header_rows: 1,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_hwp_error() {
+        assert!(matches!(
+            parse_hwp(b"not a compound file"),
+            Err(BraillifyError::DocumentError { .. })
+        ));
+    }
+}
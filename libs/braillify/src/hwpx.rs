@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use crate::{
+    archive::{Archive, find_all},
+    book::{Block, BookConfig, Volume, format_book},
+    equation::equation_run,
+    error::BraillifyError,
+    rich::{Run, push_text, trim_runs},
+    table::{Table, TableCell},
+    xml::{XmlElement, XmlNode, parse_xml},
+};
+
+/// `header.xml`의 글자 모양(`<hh:charPr>`)을 런의 서식으로 읽는다.
+fn char_styles(header: &[XmlNode]) -> HashMap<String, Run> {
+    let has = |element: &XmlElement, name: &str, none: &str| {
+        element
+            .elements()
+            .find(|e| e.local_name() == name)
+            .is_some_and(|e| e.attribute(none).is_none_or(|value| value != "NONE"))
+    };
+    find_all(header, "charPr")
+        .into_iter()
+        .filter_map(|pr| {
+            Some((
+                pr.attribute("id")?.to_string(),
+                Run {
+                    bold: has(pr, "bold", ""),
+                    italic: has(pr, "italic", ""),
+                    underline: has(pr, "underline", "type"),
+                    strike: has(pr, "strikeout", "shape"),
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect()
+}
+
+/// "개요 1"(Outline 1) 같은 개요 스타일을 제목의 단계로 읽는다.
+fn heading_styles(header: &[XmlNode]) -> HashMap<String, u8> {
+    find_all(header, "style")
+        .into_iter()
+        .filter_map(|style| {
+            let level = [("engName", "Outline "), ("name", "개요 ")]
+                .iter()
+                .find_map(|(attribute, prefix)| {
+                    style
+                        .attribute(attribute)?
+                        .strip_prefix(prefix)?
+                        .trim()
+                        .parse::<u8>()
+                        .ok()
+                })?;
+            Some((style.attribute("id")?.to_string(), level.clamp(1, 6)))
+        })
+        .collect()
+}
+
+/// 문단 안 `<hp:t>`의 글을 모두 이어 붙인다. 표 칸처럼 서식 없이 글만 필요할 때 쓴다.
+fn plain_text(element: &XmlElement) -> String {
+    find_all(&element.children, "t")
+        .iter()
+        .map(|t| t.text())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn parse_table(tbl: &XmlElement) -> Table {
+    let mut table = Table::default();
+    let mut in_header = true;
+    for tr in tbl.elements().filter(|e| e.local_name() == "tr") {
+        let cells = tr
+            .elements()
+            .filter(|e| e.local_name() == "tc")
+            .collect::<Vec<_>>();
+        if in_header
+            && !cells.is_empty()
+            && cells.iter().all(|tc| tc.attribute("header") == Some("1"))
+        {
+            table.header_rows += 1;
+        } else {
+            in_header = false;
+        }
+        table.rows.push(
+            cells
+                .into_iter()
+                .map(|tc| {
+                    let text = find_all(&tc.children, "p")
+                        .iter()
+                        .map(|p| plain_text(p))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let span = tc.elements().find(|e| e.local_name() == "cellSpan");
+                    let span = |name: &str| {
+                        span.and_then(|span| span.attribute(name)?.parse().ok())
+                            .filter(|span| *span > 0)
+                            .unwrap_or(1)
+                    };
+                    TableCell {
+                        row_span: span("rowSpan"),
+                        col_span: span("colSpan"),
+                        ..TableCell::new(text.split_whitespace().collect::<Vec<_>>().join(" "))
+                    }
+                })
+                .collect(),
+        );
+    }
+    table
+}
+
+/// 구역 XML의 문단을 `Block`으로 모은다.
+struct Builder {
+    blocks: Vec<Block>,
+    char_styles: HashMap<String, Run>,
+    heading_styles: HashMap<String, u8>,
+}
+
+impl Builder {
+    fn push_block(&mut self, runs: &mut Vec<Run>, level: Option<u8>) {
+        let runs = trim_runs(std::mem::take(runs));
+        if runs.is_empty() {
+            return;
+        }
+        self.blocks.push(match level {
+            Some(level) => Block::Heading { level, runs },
+            None => Block::Paragraph(runs),
+        });
+    }
+
+    /// `<hp:p>` 문단. 문단 안의 표는 앞뒤 글과 나누어 따로 적는다.
+    fn paragraph(&mut self, p: &XmlElement) {
+        let level = p
+            .attribute("styleIDRef")
+            .and_then(|id| self.heading_styles.get(id))
+            .copied();
+        let mut runs = vec![];
+        for run in p.elements().filter(|e| e.local_name() == "run") {
+            let style = run
+                .attribute("charPrIDRef")
+                .and_then(|id| self.char_styles.get(id))
+                .cloned()
+                .unwrap_or_default();
+            for child in run.elements() {
+                match child.local_name() {
+                    "t" => {
+                        for node in &child.children {
+                            match node {
+                                XmlNode::Text(text) => push_text(&mut runs, text, &style),
+                                XmlNode::Element(element) => match element.local_name() {
+                                    "lineBreak" => push_text(&mut runs, "\n", &style),
+                                    "tab" | "nbSpace" | "fwSpace" => {
+                                        push_text(&mut runs, " ", &style)
+                                    }
+                                    "hyphen" => push_text(&mut runs, "-", &style),
+                                    _ => {}
+                                },
+                            }
+                        }
+                    }
+                    "tbl" => {
+                        self.push_block(&mut runs, level);
+                        self.blocks.push(Block::Table(parse_table(child)));
+                    }
+                    "equation" => {
+                        let script = child
+                            .elements()
+                            .find(|e| e.local_name() == "script")
+                            .map(XmlElement::text)
+                            .unwrap_or_default();
+                        runs.push(equation_run(&script));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.push_block(&mut runs, level);
+    }
+}
+
+/// HWPX(OWPML) 문서를 구역 순서대로 읽어 점자 책의 덩어리로 나눈다.
+/// 진하게, 기울임, 밑줄, 취소선은 강조 글자체로, 수식 스크립트는 수식으로 적는다.
+pub fn parse_hwpx(bytes: &[u8]) -> Result<Vec<Block>, BraillifyError> {
+    let mut archive = Archive::new(bytes, "HWPX")?;
+    let header = if archive.contains("Contents/header.xml") {
+        parse_xml(&archive.read("Contents/header.xml")?)?
+    } else {
+        vec![]
+    };
+    let mut builder = Builder {
+        blocks: vec![],
+        char_styles: char_styles(&header),
+        heading_styles: heading_styles(&header),
+    };
+    let mut section = 0;
+    while archive.contains(&format!("Contents/section{section}.xml")) {
+        let nodes = parse_xml(&archive.read(&format!("Contents/section{section}.xml"))?)?;
+        // 표 칸 안의 문단은 표에서 읽으므로 구역의 최상위 문단만 따라간다.
+        for sec in find_all(&nodes, "sec") {
+            for p in sec.elements().filter(|e| e.local_name() == "p") {
+                builder.paragraph(p);
+            }
+        }
+        section += 1;
+    }
+    if section == 0 {
+        return Err(archive.error("section not found"));
+    }
+    Ok(builder.blocks)
+}
+
+/// HWPX 문서를 점자 책으로 배치한다.
+pub fn encode_hwpx(bytes: &[u8], config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_hwpx(bytes)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::archive::build_zip;
+
+    const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<hh:head xmlns:hh="http://www.hancom.co.kr/hwpml/2011/head"><hh:refList>
+<hh:charProperties itemCnt="3">
+  <hh:charPr id="0" height="1000"><hh:underline type="NONE" shape="SOLID"/><hh:strikeout shape="NONE"/></hh:charPr>
+  <hh:charPr id="1" height="1000"><hh:bold/><hh:underline type="NONE" shape="SOLID"/></hh:charPr>
+  <hh:charPr id="2" height="1000"><hh:underline type="BOTTOM" shape="SOLID"/></hh:charPr>
+</hh:charProperties>
+<hh:styles itemCnt="2">
+  <hh:style id="0" type="PARA" name="바탕글" engName="Normal"/>
+  <hh:style id="2" type="PARA" name="개요 1" engName="Outline 1"/>
+</hh:styles>
+</hh:refList></hh:head>"#;
+
+    const SECTION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
+<hp:p id="0" styleIDRef="2"><hp:run charPrIDRef="0"><hp:secPr/><hp:t>제목</hp:t></hp:run></hp:p>
+<hp:p id="1" styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>서울<hp:nbSpace/>특별시는<hp:fwSpace/>한국<hp:hyphen/>수도 </hp:t></hp:run><hp:run charPrIDRef="1"><hp:t>수도</hp:t></hp:run><hp:run charPrIDRef="2"><hp:t>이다</hp:t></hp:run><hp:linesegarray/></hp:p>
+<hp:p id="2" styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>값은 </hp:t><hp:equation><hp:script>1 over 2</hp:script></hp:equation>
+<hp:tbl rowCnt="2" colCnt="2">
+  <hp:tr><hp:tc header="1"><hp:subList><hp:p><hp:run><hp:t>과일</hp:t></hp:run></hp:p></hp:subList><hp:cellSpan colSpan="1" rowSpan="1"/></hp:tc><hp:tc header="1"><hp:subList><hp:p><hp:run><hp:t>값</hp:t></hp:run></hp:p></hp:subList></hp:tc></hp:tr>
+  <hp:tr><hp:tc><hp:subList><hp:p><hp:run><hp:t>사과</hp:t></hp:run></hp:p></hp:subList><hp:cellSpan colSpan="2" rowSpan="1"/></hp:tc></hp:tr>
+</hp:tbl></hp:run></hp:p>
+</hs:sec>"#;
+
+    #[test]
+    pub fn test_parse_hwpx() {
+        let hwpx = build_zip(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/header.xml", HEADER),
+            ("Contents/section0.xml", SECTION),
+        ]);
+        assert_eq!(
+            parse_hwpx(&hwpx).unwrap(),
+            vec![
+                Block::heading(1, "제목"),
+                Block::Paragraph(vec![
+                    Run::new("서울 특별시는 한국-수도 "),
+                    Run {
+                        bold: true,
+                        ..Run::new("수도")
+                    },
+                    Run {
+                        underline: true,
+                        ..Run::new("이다")
+                    },
+                ]),
+                Block::Paragraph(vec![
+                    Run::new("값은 "),
+                    Run {
+                        math: true,
+                        ..Run::new("\\frac{1}{2}")
+                    },
+                ]),
+                Block::Table(Table {
+                    rows: vec![
+                        vec![TableCell::new("과일"), TableCell::new("값")],
+                        vec![TableCell {
+                            col_span: 2,
+                            ..TableCell::new("사과")
+                        }],
+                    ],
+                    header_rows: 1,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_hwpx_error() {
+        assert!(matches!(
+            parse_hwpx(&build_zip(&[("mimetype", "application/hwp+zip")])),
+            Err(BraillifyError::DocumentError { .. })
+        ));
+    }
+}
@@ -0,0 +1,312 @@
+use phf::phf_map;
+
+use crate::{error::BraillifyError, unicode::decode_unicode};
+
+/// 외국어 점자 일람표 - 국어 문장 안에 일본어가 나올 때에는 그 앞에 일본어 표시를 적는다.
+pub const JAPANESE_START: [u8; 2] = [decode_unicode('⠨'), decode_unicode('⠴')];
+/// 외국어 점자 일람표 - 일본어 구간이 끝나고 국어가 이어질 때에는 일본어 종료표를 적는다.
+pub const JAPANESE_END: [u8; 2] = [decode_unicode('⠨'), decode_unicode('⠲')];
+
+/// 탁음부
+const DAKUTEN: u8 = decode_unicode('⠐');
+/// 반탁음부
+const HANDAKUTEN: u8 = decode_unicode('⠠');
+/// 요음부
+const YOON: u8 = decode_unicode('⠈');
+/// 요탁음부
+const YOON_DAKUTEN: u8 = decode_unicode('⠘');
+/// 요반탁음부
+const YOON_HANDAKUTEN: u8 = decode_unicode('⠨');
+/// 합요음부
+const GOYOON: u8 = decode_unicode('⠢');
+/// 합요탁음부
+const GOYOON_DAKUTEN: u8 = decode_unicode('⠲');
+/// 숫자 뒤에 ア행, ラ행 글자가 이어질 때 적는 연결부
+const TSUNAGI: u8 = decode_unicode('⠤');
+
+/// 청음(맑은소리) 가나의 점형. 가타카나는 히라가나로 바꾸어 찾는다.
+static KANA_MAP: phf::Map<char, u8> = phf_map! {
+    'あ' => decode_unicode('⠁'),
+    'い' => decode_unicode('⠃'),
+    'う' => decode_unicode('⠉'),
+    'え' => decode_unicode('⠋'),
+    'お' => decode_unicode('⠊'),
+    'か' => decode_unicode('⠡'),
+    'き' => decode_unicode('⠣'),
+    'く' => decode_unicode('⠩'),
+    'け' => decode_unicode('⠫'),
+    'こ' => decode_unicode('⠪'),
+    'さ' => decode_unicode('⠱'),
+    'し' => decode_unicode('⠳'),
+    'す' => decode_unicode('⠹'),
+    'せ' => decode_unicode('⠻'),
+    'そ' => decode_unicode('⠺'),
+    'た' => decode_unicode('⠕'),
+    'ち' => decode_unicode('⠗'),
+    'つ' => decode_unicode('⠝'),
+    'て' => decode_unicode('⠟'),
+    'と' => decode_unicode('⠞'),
+    'な' => decode_unicode('⠅'),
+    'に' => decode_unicode('⠇'),
+    'ぬ' => decode_unicode('⠍'),
+    'ね' => decode_unicode('⠏'),
+    'の' => decode_unicode('⠎'),
+    'は' => decode_unicode('⠥'),
+    'ひ' => decode_unicode('⠧'),
+    'ふ' => decode_unicode('⠭'),
+    'へ' => decode_unicode('⠯'),
+    'ほ' => decode_unicode('⠮'),
+    'ま' => decode_unicode('⠵'),
+    'み' => decode_unicode('⠷'),
+    'む' => decode_unicode('⠽'),
+    'め' => decode_unicode('⠿'),
+    'も' => decode_unicode('⠾'),
+    'や' => decode_unicode('⠌'),
+    'ゆ' => decode_unicode('⠬'),
+    'よ' => decode_unicode('⠜'),
+    'ら' => decode_unicode('⠑'),
+    'り' => decode_unicode('⠓'),
+    'る' => decode_unicode('⠙'),
+    'れ' => decode_unicode('⠛'),
+    'ろ' => decode_unicode('⠚'),
+    'わ' => decode_unicode('⠄'),
+    'ゐ' => decode_unicode('⠆'),
+    'ゑ' => decode_unicode('⠖'),
+    'を' => decode_unicode('⠔'),
+    'ん' => decode_unicode('⠴'),
+    // 촉음부
+    'っ' => decode_unicode('⠂'),
+    // 장음부
+    'ー' => decode_unicode('⠒'),
+    '。' => decode_unicode('⠲'),
+    '、' => decode_unicode('⠰'),
+    '・' => decode_unicode('⠐'),
+};
+
+/// 탁음·반탁음 가나를 청음 가나와 탁점 종류로 나눈다.
+static VOICED_MAP: phf::Map<char, (char, u8)> = phf_map! {
+    'が' => ('か', DAKUTEN), 'ぎ' => ('き', DAKUTEN), 'ぐ' => ('く', DAKUTEN), 'げ' => ('け', DAKUTEN), 'ご' => ('こ', DAKUTEN),
+    'ざ' => ('さ', DAKUTEN), 'じ' => ('し', DAKUTEN), 'ず' => ('す', DAKUTEN), 'ぜ' => ('せ', DAKUTEN), 'ぞ' => ('そ', DAKUTEN),
+    'だ' => ('た', DAKUTEN), 'ぢ' => ('ち', DAKUTEN), 'づ' => ('つ', DAKUTEN), 'で' => ('て', DAKUTEN), 'ど' => ('と', DAKUTEN),
+    'ば' => ('は', DAKUTEN), 'び' => ('ひ', DAKUTEN), 'ぶ' => ('ふ', DAKUTEN), 'べ' => ('へ', DAKUTEN), 'ぼ' => ('ほ', DAKUTEN),
+    'ぱ' => ('は', HANDAKUTEN), 'ぴ' => ('ひ', HANDAKUTEN), 'ぷ' => ('ふ', HANDAKUTEN), 'ぺ' => ('へ', HANDAKUTEN), 'ぽ' => ('ほ', HANDAKUTEN),
+    'ゔ' => ('う', DAKUTEN),
+};
+
+/// 작은 글자는 보통 글자와 같은 점형으로 적는다(요음으로 쓰인 경우는 제외).
+static SMALL_KANA_MAP: phf::Map<char, char> = phf_map! {
+    'ぁ' => 'あ', 'ぃ' => 'い', 'ぅ' => 'う', 'ぇ' => 'え', 'ぉ' => 'お',
+    'ゃ' => 'や', 'ゅ' => 'ゆ', 'ょ' => 'よ', 'ゎ' => 'わ', 'ゕ' => 'か', 'ゖ' => 'け',
+};
+
+/// 요음 - イ단 글자 뒤에 작은 ゃ, ゅ, ょ가 붙으면 같은 행의 ア단, ウ단, オ단 점형 앞에 요음부를 적는다.
+static YOON_ROW_MAP: phf::Map<char, [char; 3]> = phf_map! {
+    'き' => ['か', 'く', 'こ'],
+    'し' => ['さ', 'す', 'そ'],
+    'ち' => ['た', 'つ', 'と'],
+    'に' => ['な', 'ぬ', 'の'],
+    'ひ' => ['は', 'ふ', 'ほ'],
+    'み' => ['ま', 'む', 'も'],
+    'り' => ['ら', 'る', 'ろ'],
+};
+
+/// 특수음 - 외래어를 적는 두 글자 소리는 요음부나 합요음부 뒤에 한 글자를 적는다.
+static SPECIAL_SOUND_MAP: phf::Map<&'static str, (u8, char)> = phf_map! {
+    "いぇ" => (YOON, 'え'),
+    "きぇ" => (YOON, 'け'), "しぇ" => (YOON, 'せ'), "ちぇ" => (YOON, 'て'), "にぇ" => (YOON, 'ね'), "ひぇ" => (YOON, 'へ'),
+    "じぇ" => (YOON_DAKUTEN, 'せ'), "ぢぇ" => (YOON_DAKUTEN, 'て'),
+    "てぃ" => (YOON, 'ち'), "でぃ" => (YOON_DAKUTEN, 'ち'),
+    "うぃ" => (GOYOON, 'い'), "うぇ" => (GOYOON, 'え'), "うぉ" => (GOYOON, 'お'),
+    "とぅ" => (GOYOON, 'つ'), "どぅ" => (GOYOON_DAKUTEN, 'つ'),
+    "くぁ" => (GOYOON, 'か'), "くぃ" => (GOYOON, 'き'), "くぇ" => (GOYOON, 'け'), "くぉ" => (GOYOON, 'こ'),
+    "ぐぁ" => (GOYOON_DAKUTEN, 'か'), "ぐぃ" => (GOYOON_DAKUTEN, 'き'), "ぐぇ" => (GOYOON_DAKUTEN, 'け'), "ぐぉ" => (GOYOON_DAKUTEN, 'こ'),
+    "つぁ" => (GOYOON, 'た'), "つぃ" => (GOYOON, 'ち'), "つぇ" => (GOYOON, 'て'), "つぉ" => (GOYOON, 'と'),
+    "ふぁ" => (GOYOON, 'は'), "ふぃ" => (GOYOON, 'ひ'), "ふぇ" => (GOYOON, 'へ'), "ふぉ" => (GOYOON, 'ほ'),
+    "ゔぁ" => (GOYOON_DAKUTEN, 'は'), "ゔぃ" => (GOYOON_DAKUTEN, 'ひ'), "ゔぇ" => (GOYOON_DAKUTEN, 'へ'), "ゔぉ" => (GOYOON_DAKUTEN, 'ほ'),
+};
+
+/// 가타카나를 같은 소리의 히라가나로 바꾼다.
+fn to_hiragana(c: char) -> char {
+    if ('\u{30A1}'..='\u{30F6}').contains(&c) {
+        char::from_u32(c as u32 - 0x60).unwrap_or(c)
+    } else {
+        c
+    }
+}
+
+pub fn is_japanese_char(c: char) -> bool {
+    let c = to_hiragana(c);
+    KANA_MAP.contains_key(&c)
+        || VOICED_MAP.contains_key(&c)
+        || SMALL_KANA_MAP.contains_key(&c)
+        || ['ヷ', 'ヸ', 'ヹ', 'ヺ'].contains(&c)
+}
+
+fn encode_plain_kana(c: char) -> Result<u8, BraillifyError> {
+    let c = SMALL_KANA_MAP.get(&c).copied().unwrap_or(c);
+    KANA_MAP
+        .get(&c)
+        .copied()
+        .ok_or(BraillifyError::InvalidCharacter {
+            character: c,
+            position: None,
+            context: "Unsupported Japanese kana".to_string(),
+        })
+}
+
+/// 숫자 뒤에서 숫자로 오인될 수 있는 ア행, ラ행 글자인지 확인한다.
+fn is_confusable_after_number(c: char) -> bool {
+    matches!(
+        c,
+        'あ' | 'い' | 'う' | 'え' | 'お' | 'ら' | 'り' | 'る' | 'れ' | 'ろ'
+    )
+}
+
+/// 가나 한 글자(뒤에 요음이나 특수음의 작은 글자가 붙으면 두 글자)를 점형으로 바꾸고 사용한 글자 수를 돌려준다.
+/// `after_number`가 참이면 숫자 뒤에 이어지는 가나로 보고 필요한 경우 연결부를 적는다.
+pub fn encode_japanese(
+    kana: char,
+    next: Option<char>,
+    after_number: bool,
+) -> Result<(Vec<u8>, usize), BraillifyError> {
+    let c = match to_hiragana(kana) {
+        // ヷ, ヸ, ヹ, ヺ 는 ワ행 글자에 탁음부를 붙여 적는다.
+        'ヷ' => return Ok((vec![DAKUTEN, encode_plain_kana('わ')?], 1)),
+        'ヸ' => return Ok((vec![DAKUTEN, encode_plain_kana('ゐ')?], 1)),
+        'ヹ' => return Ok((vec![DAKUTEN, encode_plain_kana('ゑ')?], 1)),
+        'ヺ' => return Ok((vec![DAKUTEN, encode_plain_kana('を')?], 1)),
+        c => c,
+    };
+    let next = next.map(to_hiragana);
+    if let Some(next) = next
+        && let Some((prefix, column)) = SPECIAL_SOUND_MAP.get(String::from_iter([c, next]).as_str())
+    {
+        return Ok((vec![*prefix, encode_plain_kana(*column)?], 2));
+    }
+
+    let (base, mark) = match VOICED_MAP.get(&c) {
+        Some((base, mark)) => (*base, Some(*mark)),
+        None => (c, None),
+    };
+
+    if let (Some(row), Some(small @ ('ゃ' | 'ゅ' | 'ょ'))) = (YOON_ROW_MAP.get(&base), next) {
+        let column = match small {
+            'ゃ' => row[0],
+            'ゅ' => row[1],
+            _ => row[2],
+        };
+        let prefix = match mark {
+            Some(DAKUTEN) => YOON_DAKUTEN,
+            Some(_) => YOON_HANDAKUTEN,
+            None => YOON,
+        };
+        return Ok((vec![prefix, encode_plain_kana(column)?], 2));
+    }
+
+    let mut result = vec![];
+    if after_number && mark.is_none() && is_confusable_after_number(base) {
+        result.push(TSUNAGI);
+    }
+    if let Some(mark) = mark {
+        result.push(mark);
+    }
+    result.push(encode_plain_kana(base)?);
+    Ok((result, 1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode_str(text: &str) -> Vec<u8> {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut result = vec![];
+        let mut i = 0;
+        while i < chars.len() {
+            let (code, len) = encode_japanese(chars[i], chars.get(i + 1).copied(), false).unwrap();
+            result.extend(code);
+            i += len;
+        }
+        result
+    }
+
+    #[test]
+    pub fn test_is_japanese_char() {
+        assert!(is_japanese_char('あ'));
+        assert!(is_japanese_char('ア'));
+        assert!(is_japanese_char('が'));
+        assert!(is_japanese_char('ょ'));
+        assert!(is_japanese_char('ー'));
+        assert!(is_japanese_char('ヴ'));
+        assert!(!is_japanese_char('가'));
+        assert!(!is_japanese_char('漢'));
+        assert!(!is_japanese_char('a'));
+    }
+
+    #[test]
+    pub fn test_encode_japanese() {
+        assert_eq!(encode_str("あいうえお"), vec![1, 3, 9, 11, 10]);
+        assert_eq!(encode_str("が"), vec![DAKUTEN, decode_unicode('⠡')]);
+        assert_eq!(encode_str("パ"), vec![HANDAKUTEN, decode_unicode('⠥')]);
+        assert_eq!(encode_str("きゃ"), vec![YOON, decode_unicode('⠡')]);
+        assert_eq!(encode_str("ジュ"), vec![YOON_DAKUTEN, decode_unicode('⠹')]);
+        assert_eq!(
+            encode_str("ぴょ"),
+            vec![YOON_HANDAKUTEN, decode_unicode('⠮')]
+        );
+        assert_eq!(
+            encode_str("ちょっと"),
+            vec![
+                YOON,
+                decode_unicode('⠞'),
+                decode_unicode('⠂'),
+                decode_unicode('⠞')
+            ]
+        );
+        assert_eq!(
+            encode_str("コーヒー"),
+            vec![
+                decode_unicode('⠪'),
+                decode_unicode('⠒'),
+                decode_unicode('⠧'),
+                decode_unicode('⠒')
+            ]
+        );
+        assert!(encode_japanese('漢', None, false).is_err());
+    }
+
+    #[test]
+    pub fn test_encode_special_sound() {
+        assert_eq!(
+            encode_str("ファイル"),
+            vec![
+                GOYOON,
+                decode_unicode('⠥'),
+                decode_unicode('⠃'),
+                decode_unicode('⠙')
+            ]
+        );
+        assert_eq!(encode_str("ティ"), vec![YOON, decode_unicode('⠗')]);
+        assert_eq!(encode_str("ディ"), vec![YOON_DAKUTEN, decode_unicode('⠗')]);
+        assert_eq!(encode_str("ウィ"), vec![GOYOON, decode_unicode('⠃')]);
+        assert_eq!(encode_str("シェ"), vec![YOON, decode_unicode('⠻')]);
+        assert_eq!(
+            encode_str("ヴァ"),
+            vec![GOYOON_DAKUTEN, decode_unicode('⠥')]
+        );
+        // 특수음이 아닌 작은 글자는 보통 글자와 같이 적는다.
+        assert_eq!(encode_str("ぁ"), vec![decode_unicode('⠁')]);
+    }
+
+    #[test]
+    pub fn test_encode_japanese_after_number() {
+        assert_eq!(
+            encode_japanese('え', None, true).unwrap(),
+            (vec![TSUNAGI, decode_unicode('⠋')], 1)
+        );
+        assert_eq!(
+            encode_japanese('つ', None, true).unwrap(),
+            (vec![decode_unicode('⠝')], 1)
+        );
+    }
+}
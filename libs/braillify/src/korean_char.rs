@@ -65,3 +65,21 @@ pub fn encode_korean_char(korean: &KoreanChar) -> Result<Vec<u8>, BraillifyError
 
     Ok(result)
 }
+
+/// 약자를 쓰지 않고 첫소리, 모음, 받침을 차례로 적는다.
+pub fn encode_korean_char_uncontracted(korean: &KoreanChar) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = Vec::new();
+    let (cho0, cho1) = split_korean_jauem(korean.cho)?;
+    if cho1.is_some() {
+        // 된소리표
+        result.push(32);
+    }
+    if cho0 != 'ㅇ' {
+        result.push(encode_choseong(cho0)?);
+    }
+    result.extend(encode_jungsong(korean.jung)?);
+    if let Some(jong) = korean.jong {
+        result.extend(encode_jongseong(jong)?);
+    }
+    Ok(result)
+}
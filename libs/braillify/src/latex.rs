@@ -0,0 +1,385 @@
+use crate::{
+    error::BraillifyError,
+    math::{FUNCTIONS, MathNode, encode_math},
+};
+
+/// LaTeX 명령어 이름을 그리스 문자로 바꾼다.
+pub(crate) fn greek_letter(name: &str) -> Option<char> {
+    Some(match name {
+        "alpha" => 'α',
+        "beta" => 'β',
+        "gamma" => 'γ',
+        "delta" => 'δ',
+        "epsilon" | "varepsilon" => 'ε',
+        "zeta" => 'ζ',
+        "eta" => 'η',
+        "theta" | "vartheta" => 'θ',
+        "iota" => 'ι',
+        "kappa" => 'κ',
+        "lambda" => 'λ',
+        "mu" => 'μ',
+        "nu" => 'ν',
+        "xi" => 'ξ',
+        "pi" => 'π',
+        "rho" => 'ρ',
+        "sigma" => 'σ',
+        "tau" => 'τ',
+        "upsilon" => 'υ',
+        "phi" | "varphi" => 'φ',
+        "chi" => 'χ',
+        "psi" => 'ψ',
+        "omega" => 'ω',
+        "Gamma" => 'Γ',
+        "Delta" => 'Δ',
+        "Theta" => 'Θ',
+        "Lambda" => 'Λ',
+        "Xi" => 'Ξ',
+        "Pi" => 'Π',
+        "Sigma" => 'Σ',
+        "Phi" => 'Φ',
+        "Psi" => 'Ψ',
+        "Omega" => 'Ω',
+        _ => return None,
+    })
+}
+
+/// LaTeX 명령어 이름을 연산 기호로 바꾼다.
+pub(crate) fn operator(name: &str) -> Option<char> {
+    Some(match name {
+        "times" => '×',
+        "div" => '÷',
+        "cdot" => '·',
+        "pm" => '±',
+        "le" | "leq" => '≤',
+        "ge" | "geq" => '≥',
+        "ne" | "neq" => '≠',
+        "lt" => '<',
+        "gt" => '>',
+        "mp" => '∓',
+        "infty" => '∞',
+        "sum" => '∑',
+        "int" => '∫',
+        "angle" => '∠',
+        "perp" => '⊥',
+        "parallel" => '∥',
+        "in" => '∈',
+        "subset" => '⊂',
+        "cup" => '∪',
+        "cap" => '∩',
+        "therefore" => '∴',
+        "because" => '∵',
+        "circ" => '°',
+        "prime" => '′',
+        _ => return None,
+    })
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn error(&self, error: impl Into<String>) -> BraillifyError {
+        BraillifyError::MathParseError {
+            input: self.input.to_string(),
+            error: error.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), BraillifyError> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}' at {}", c, self.pos)))
+        }
+    }
+
+    /// `end`가 나올 때까지 읽는다. `end`가 None이면 입력 끝까지 읽는다.
+    fn parse_sequence(&mut self, end: Option<char>) -> Result<Vec<MathNode>, BraillifyError> {
+        let mut nodes = vec![];
+        loop {
+            self.skip_whitespace();
+            let Some(c) = self.peek() else {
+                return match end {
+                    Some(end) => Err(self.error(format!("missing '{}'", end))),
+                    None => Ok(nodes),
+                };
+            };
+            if Some(c) == end {
+                self.pos += 1;
+                return Ok(nodes);
+            }
+            match c {
+                '^' | '_' => {
+                    self.pos += 1;
+                    let script = self.parse_argument()?;
+                    // 30^\circ, f^\prime 처럼 위첨자로 쓴 도(°)와 프라임(′)은 그대로 적는다.
+                    if c == '^' && matches!(script[..], [MathNode::Operator('°' | '′')]) {
+                        nodes.extend(script);
+                        continue;
+                    }
+                    nodes.push(if c == '^' {
+                        MathNode::Superscript(script)
+                    } else {
+                        MathNode::Subscript(script)
+                    });
+                }
+                '}' | ')' | ']' => {
+                    return Err(self.error(format!("unexpected '{}' at {}", c, self.pos)));
+                }
+                _ => nodes.extend(self.parse_atom()?),
+            }
+        }
+    }
+
+    /// 첨자, 분자, 분모처럼 명령어 뒤에 오는 인자 하나를 읽는다.
+    fn parse_argument(&mut self) -> Result<Vec<MathNode>, BraillifyError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => {
+                self.pos += 1;
+                self.parse_sequence(Some('}'))
+            }
+            // 중괄호 없이 쓴 숫자 인자는 한 자리만 취한다. (x^23 = x^{2}3)
+            Some(c) if c.is_ascii_digit() => {
+                self.pos += 1;
+                Ok(vec![MathNode::Number(c.to_string())])
+            }
+            Some(_) => self.parse_atom(),
+            None => Err(self.error("missing argument")),
+        }
+    }
+
+    fn parse_number(&mut self) -> MathNode {
+        let mut number = String::new();
+        while let Some(c) = self.peek() {
+            let is_decimal_point = c == '.'
+                && self
+                    .chars
+                    .get(self.pos + 1)
+                    .is_some_and(|next| next.is_ascii_digit());
+            if !(c.is_ascii_digit() || is_decimal_point) {
+                break;
+            }
+            number.push(c);
+            self.pos += 1;
+        }
+        MathNode::Number(number)
+    }
+
+    fn parse_atom(&mut self) -> Result<Vec<MathNode>, BraillifyError> {
+        let Some(c) = self.peek() else {
+            return Err(self.error("unexpected end of input"));
+        };
+        if c.is_ascii_digit() {
+            return Ok(vec![self.parse_number()]);
+        }
+        self.pos += 1;
+        Ok(match c {
+            '\\' => return self.parse_command(),
+            '{' => self.parse_sequence(Some('}'))?,
+            '(' => vec![MathNode::Fenced('(', self.parse_sequence(Some(')'))?, ')')],
+            '[' => vec![MathNode::Fenced('[', self.parse_sequence(Some(']'))?, ']')],
+            '-' => vec![MathNode::Operator('−')],
+            '*' => vec![MathNode::Operator('×')],
+            '+' | '=' | '<' | '>' | '/' | ',' | '.' | '!' | '?' | ':' | ';' => {
+                vec![MathNode::Operator(c)]
+            }
+            c if c.is_ascii_alphabetic() => vec![MathNode::Identifier(c)],
+            _ => return Err(self.error(format!("unsupported character '{}'", c))),
+        })
+    }
+
+    fn parse_command(&mut self) -> Result<Vec<MathNode>, BraillifyError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            // \, \; \! \: \  같은 간격 명령어는 점자에서 무시한다.
+            return match self.peek() {
+                Some(',' | ';' | '!' | ':' | ' ') => {
+                    self.pos += 1;
+                    Ok(vec![])
+                }
+                _ => Err(self.error(format!("invalid command at {}", start))),
+            };
+        }
+        let name = self.chars[start..self.pos].iter().collect::<String>();
+        match name.as_str() {
+            "frac" | "dfrac" | "tfrac" => {
+                let numerator = self.parse_argument()?;
+                let denominator = self.parse_argument()?;
+                Ok(vec![MathNode::Fraction(numerator, denominator)])
+            }
+            "sqrt" => {
+                self.skip_whitespace();
+                let index = if self.peek() == Some('[') {
+                    self.pos += 1;
+                    Some(self.parse_sequence(Some(']'))?)
+                } else {
+                    None
+                };
+                let radicand = self.parse_argument()?;
+                Ok(vec![MathNode::Root(index, radicand)])
+            }
+            "left" | "right" | "quad" | "qquad" => Ok(vec![]),
+            "mathrm" | "mathit" => {
+                self.expect('{')?;
+                self.parse_sequence(Some('}'))
+            }
+            "text" => {
+                self.expect('{')?;
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c != '}') {
+                    self.pos += 1;
+                }
+                let text = self.chars[start..self.pos].iter().collect::<String>();
+                self.expect('}')?;
+                Ok(vec![MathNode::Text(text)])
+            }
+            _ => {
+                if let Some(c) = greek_letter(&name) {
+                    Ok(vec![MathNode::Identifier(c)])
+                } else if let Some(c) = operator(&name) {
+                    Ok(vec![MathNode::Operator(c)])
+                } else if FUNCTIONS.contains(&name.as_str()) {
+                    Ok(vec![MathNode::Function(name)])
+                } else {
+                    Err(self.error(format!("unsupported command '\\{}'", name)))
+                }
+            }
+        }
+    }
+}
+
+/// `$…$` 안의 LaTeX 수식을 읽어 수식 구조로 바꾼다.
+pub fn parse_latex_math(s: &str) -> Result<Vec<MathNode>, BraillifyError> {
+    Parser::new(s).parse_sequence(None)
+}
+
+pub fn encode_latex_math(s: &str) -> Result<Vec<u8>, BraillifyError> {
+    encode_math(&parse_latex_math(s)?)
+}
+
+/// 단어가 `$…$` 수식으로 시작하면 수식 부분과 뒤에 붙은 나머지(조사 등)로 나눈다.
+pub fn split_math_span(word: &str) -> Option<(&str, &str)> {
+    let inner = word.strip_prefix('$')?;
+    let end = inner.find('$')?;
+    if end == 0 {
+        return None;
+    }
+    Some((&inner[..end], &inner[end + 1..]))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(s: &str) -> String {
+        encode_latex_math(s)
+            .unwrap()
+            .iter()
+            .map(|c| encode_unicode(*c))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_parse_latex_math() {
+        assert_eq!(
+            parse_latex_math("x^2").unwrap(),
+            vec![
+                MathNode::Identifier('x'),
+                MathNode::Superscript(vec![MathNode::Number("2".into())])
+            ]
+        );
+        assert_eq!(
+            parse_latex_math("x^23").unwrap(),
+            vec![
+                MathNode::Identifier('x'),
+                MathNode::Superscript(vec![MathNode::Number("2".into())]),
+                MathNode::Number("3".into())
+            ]
+        );
+        assert_eq!(
+            parse_latex_math("\\sqrt[3]{8}").unwrap(),
+            vec![MathNode::Root(
+                Some(vec![MathNode::Number("3".into())]),
+                vec![MathNode::Number("8".into())]
+            )]
+        );
+        assert_eq!(
+            parse_latex_math("\\left( a \\right)").unwrap(),
+            vec![MathNode::Fenced('(', vec![MathNode::Identifier('a')], ')')]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_latex_math_error() {
+        assert!(parse_latex_math("\\unknown").is_err());
+        assert!(parse_latex_math("\\frac{1}{2").is_err());
+        assert!(parse_latex_math("x)").is_err());
+        assert!(parse_latex_math("x^").is_err());
+    }
+
+    #[test]
+    pub fn test_encode_latex_math() {
+        assert_eq!(to_unicode("x^2"), "⠭⠘⠼⠃");
+        assert_eq!(to_unicode("x_1"), "⠭⠰⠼⠁");
+        assert_eq!(to_unicode("a+b=c"), "⠁⠢⠃⠒⠒⠉");
+        assert_eq!(to_unicode("3 \\times 4 = 12"), "⠼⠉⠡⠼⠙⠒⠒⠼⠁⠃");
+        assert_eq!(to_unicode("6 \\div 2"), "⠼⠋⠌⠌⠼⠃");
+        assert_eq!(to_unicode("x \\le 3"), "⠭⠔⠔⠒⠼⠉");
+        assert_eq!(to_unicode("x \\ge 3"), "⠭⠢⠢⠒⠼⠉");
+        assert_eq!(to_unicode("x \\neq 0"), "⠭⠸⠒⠒⠼⠚");
+        assert_eq!(to_unicode("\\pm 1"), "⠢⠔⠼⠁");
+        assert_eq!(to_unicode("2\\pi r"), "⠼⠃⠨⠏⠗");
+        assert_eq!(to_unicode("\\Omega"), "⠠⠨⠺");
+        assert_eq!(to_unicode("\\sqrt{2}"), "⠜⠼⠃⠻");
+        assert_eq!(to_unicode("\\frac{a}{b}"), "⠃⠌⠁");
+        assert_eq!(to_unicode("\\frac{1}{2}"), "⠼⠃⠌⠼⠁");
+        // 분모나 분자가 두 항 이상이면 괄호로 묶는다.
+        assert_eq!(to_unicode("\\frac{x+1}{2}"), "⠼⠃⠌⠦⠄⠭⠢⠼⠁⠠⠴");
+        assert_eq!(to_unicode("\\frac{\\frac{1}{2}}{3}"), "⠼⠉⠌⠦⠄⠼⠃⠌⠼⠁⠠⠴");
+        assert_eq!(to_unicode("\\frac{1}{\\frac{2}{3}}"), "⠦⠄⠼⠉⠌⠼⠃⠠⠴⠌⠼⠁");
+        assert_eq!(to_unicode("(a-b)^2"), "⠦⠄⠁⠔⠃⠠⠴⠘⠼⠃");
+        assert_eq!(to_unicode("\\sin x"), "⠎⠊⠝⠀⠭");
+        assert_eq!(to_unicode("\\log_2 8"), "⠇⠕⠛⠰⠼⠃⠼⠓");
+        assert_eq!(to_unicode("x^{n+1}"), "⠭⠘⠦⠄⠝⠢⠼⠁⠠⠴");
+        assert_eq!(to_unicode("\\angle A = 30^\\circ"), "⠸⠪⠠⠁⠒⠒⠼⠉⠚⠘⠚");
+        assert_eq!(to_unicode("a \\in A"), "⠁⠈⠑⠠⠁");
+        assert_eq!(to_unicode("\\mp 2"), "⠔⠢⠼⠃");
+    }
+
+    #[test]
+    pub fn test_split_math_span() {
+        assert_eq!(split_math_span("$x^2$이다."), Some(("x^2", "이다.")));
+        assert_eq!(split_math_span("$x$"), Some(("x", "")));
+        assert_eq!(split_math_span("$$"), None);
+        assert_eq!(split_math_span("x$"), None);
+    }
+}
@@ -3,48 +3,127 @@ use moeum::jungsong::encode_jungsong;
 use utils::has_choseong_o;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::ops::Range;
 
 use crate::{
     char_struct::CharType,
-    error::BraillifyError,
+    error::{BraillifyError, EncodingWarning},
     jauem::jongseong::encode_jongseong,
-    korean_char::encode_korean_char,
+    korean_char::{encode_korean_char, encode_korean_char_uncontracted},
+    particle::Preceding,
     rule::{rule_11, rule_12},
     rule_en::{rule_en_10_4, rule_en_10_6},
     split::split_korean_jauem,
 };
 
+pub use book::{Block, BookConfig, Page, Volume, format_book};
+#[cfg(feature = "docx")]
+pub use docx::{encode_docx, parse_docx};
+#[cfg(feature = "epub")]
+pub use epub::{encode_epub, parse_epub};
+pub use html::{encode_html, parse_html};
+#[cfg(feature = "hwp")]
+pub use hwp::{encode_hwp, parse_hwp};
+#[cfg(feature = "hwpx")]
+pub use hwpx::{encode_hwpx, parse_hwpx};
+pub use line::{BreakPoint, Encoded, Indent, break_lines, break_lines_indented};
+#[cfg(feature = "markdown")]
+pub use markdown::{encode_markdown, parse_markdown};
+#[cfg(feature = "odt")]
+pub use odt::{encode_odt, parse_odt};
+#[cfg(feature = "pdf")]
+pub use pdf::{PdfConfig, encode_pdf, parse_pdf};
+pub use rich::{Language, Run, encode_rich, encode_rich_with_breaks};
+pub use table::{Table, TableCell, format_table};
+
 static FRACTION_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r#"^(\d+)\/(\d+)"#)
         .expect("Failed to compile FRACTION_REGEX")
 });
 
+#[cfg(any(
+    feature = "epub",
+    feature = "hwpx",
+    feature = "docx",
+    feature = "odt"
+))]
+mod archive;
+mod book;
+mod braille;
 mod char_shortcut;
 mod char_struct;
+mod chemistry;
+mod computer;
+#[cfg(feature = "docx")]
+mod docx;
 mod error;
+mod html;
+#[cfg(feature = "hwp")]
+mod hwp;
+#[cfg(feature = "hwpx")]
+mod hwpx;
 #[cfg(feature = "cli")]
 pub mod cli;
 mod english;
 mod english_logic;
+mod enumerator;
+#[cfg(feature = "epub")]
+mod epub;
+#[cfg(any(feature = "hwp", feature = "hwpx"))]
+mod equation;
 mod jauem;
+mod japanese;
 mod korean_char;
 mod korean_part;
+mod latex;
+mod line;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod math;
+mod mathml;
 mod math_symbol_shortcut;
 mod moeum;
+mod note;
 mod number;
+mod numeric;
+#[cfg(feature = "odt")]
+mod odt;
+mod particle;
+#[cfg(feature = "pdf")]
+mod pdf;
+mod rich;
 mod rule;
 mod rule_en;
 mod split;
 mod symbol_shortcut;
+mod table;
 mod unicode;
+mod unit;
 mod utils;
 mod word_shortcut;
+mod xml;
 mod fraction;
 
+/// 빈칸과 줄 바꿈을 다루는 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhitespaceMode {
+    /// 단어 사이의 공백을 한 칸으로 줄여 적는다.
+    #[default]
+    Collapse,
+    /// 빈칸, 탭, 빈 줄을 묵자 그대로 빈칸과 줄 바꿈으로 옮긴다.
+    Preserve,
+}
+
 #[derive(Debug, Clone)]
 pub struct EncodingConfig {
     pub english_indicator: bool,
     pub max_input_length: usize,
+    pub whitespace: WhitespaceMode,
+    /// `WhitespaceMode::Preserve`에서 탭을 채울 칸 간격
+    pub tab_width: usize,
+    /// 화학 문서로 보고 원소 기호로 이루어진 로마자(NaCl, CO2)를 화학식으로 적는다.
+    /// 끄더라도 반응식 화살표가 있는 글에서는 화학식으로 적는다.
+    pub chemistry: bool,
 }
 
 impl Default for EncodingConfig {
@@ -52,6 +131,9 @@ impl Default for EncodingConfig {
         Self {
             english_indicator: true,
             max_input_length: 0,
+            whitespace: WhitespaceMode::Collapse,
+            tab_width: 8,
+            chemistry: false,
         }
     }
 }
@@ -62,7 +144,21 @@ pub struct Encoder {
     english_indicator: bool,
     has_processed_word: bool,
     needs_english_continuation: bool,
+    is_japanese: bool,
     parenthesis_stack: Vec<bool>,
+    warnings: Vec<EncodingWarning>,
+    /// 국어 약자와 약어, 로마자 약자를 쓸지 여부
+    contractions: bool,
+    whitespace: WhitespaceMode,
+    tab_width: usize,
+    /// 원소 기호로 이루어진 로마자를 화학식으로 읽을지 여부
+    chemistry: bool,
+    /// 단어 안에서 줄을 바꿀 수 있는 자리
+    breaks: Vec<BreakPoint>,
+    /// 줄을 바꾸지 않는 빈칸의 자리
+    joins: Vec<usize>,
+    /// 띄어 쓴 날짜에서 아직 적지 않은 단어 수
+    date_words: usize,
 }
 
 impl Encoder {
@@ -73,10 +169,24 @@ impl Encoder {
             triple_big_english: false,
             has_processed_word: false,
             needs_english_continuation: false,
+            is_japanese: false,
             parenthesis_stack: Vec::new(),
+            warnings: Vec::new(),
+            contractions: true,
+            whitespace: WhitespaceMode::Collapse,
+            tab_width: 8,
+            chemistry: false,
+            breaks: Vec::new(),
+            joins: Vec::new(),
+            date_words: 0,
         }
     }
 
+    /// 변환 중에 쌓인 경고를 꺼낸다.
+    pub fn take_warnings(&mut self) -> Vec<EncodingWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
     fn exit_english(&mut self, needs_continuation: bool) {
         self.is_english = false;
         self.needs_english_continuation = needs_continuation;
@@ -92,15 +202,81 @@ impl Encoder {
         self.needs_english_continuation = false;
     }
 
+    fn enter_japanese(&mut self, result: &mut Vec<u8>) {
+        if self.english_indicator {
+            result.extend(japanese::JAPANESE_START);
+        }
+        self.is_japanese = true;
+    }
+
+    fn exit_japanese(&mut self, result: &mut Vec<u8>) {
+        if self.english_indicator {
+            result.extend(japanese::JAPANESE_END);
+        }
+        self.is_japanese = false;
+    }
+
+    /// 열려 있는 로마자 구간이나 일본어 구간을 닫는다.
+    fn close_foreign(&mut self, result: &mut Vec<u8>) {
+        if self.is_english {
+            if self.english_indicator {
+                result.push(50);
+            }
+            self.exit_english(false);
+        }
+        if self.is_japanese {
+            self.exit_japanese(result);
+        }
+    }
+
+    /// 점역자 주를 넣는다. 앞말과 띄어 쓰고, 주 안의 글은 같은 규칙으로 따로 적는다.
+    /// 열려 있던 로마자 구간은 주 앞에서 닫고, 그 밖의 상태는 주를 적은 뒤에도 그대로 이어진다.
+    /// 주 뒤에 띄어 쓸 때에는 호출하는 쪽에서 빈칸(0)을 적는다.
+    pub fn insert_note(&mut self, note: &str, result: &mut Vec<u8>) -> Result<(), BraillifyError> {
+        self.close_foreign(result);
+        if result.last().is_some_and(|c| *c != 0 && *c != 255) {
+            result.push(0);
+        }
+        let mut encoder = Encoder::new(self.english_indicator);
+        encoder.contractions = self.contractions;
+        encoder.chemistry = self.chemistry;
+        result.extend(note::NOTE_START);
+        encoder.encode(note, result)?;
+        encoder.close_foreign(result);
+        encoder.finish(result)?;
+        result.extend(note::NOTE_END);
+        self.warnings.extend(encoder.take_warnings());
+        Ok(())
+    }
+
     pub fn encode(&mut self, text: &str, result: &mut Vec<u8>) -> Result<(), BraillifyError> {
-        let words = text
-            .split(' ')
-            .filter(|word| !word.is_empty())
-            .collect::<Vec<&str>>();
+        self.encode_before(text, None, result)
+    }
+
+    /// 띄어 쓴 다음 단어를 미리 보고 적는다.
+    /// 서식이 바뀌는 경계에서도 로마자 종료표를 띄어쓰기 앞에 적기 위해 쓴다.
+    fn encode_before(
+        &mut self,
+        text: &str,
+        next_word: Option<&str>,
+        result: &mut Vec<u8>,
+    ) -> Result<(), BraillifyError> {
+        if self.whitespace == WhitespaceMode::Preserve {
+            return self.encode_preserving(text, next_word, result);
+        }
+        let mut words = split_words(text);
+        let lookahead = usize::from(next_word.is_some());
+        words.extend(next_word);
+
+        // 반응식 화살표가 있는 글은 화학 문맥으로 본다.
+        let chemistry = self.chemistry;
+        self.chemistry |= words
+            .iter()
+            .any(|word| word.contains(chemistry::REACTION_ARROWS));
 
         let mut word: &str = "";
         let mut remaining_words = &words[..];
-        while !remaining_words.is_empty() {
+        while remaining_words.len() > lookahead {
             let prev_word = word;
             (word, remaining_words) = remaining_words.split_first().unwrap();
 
@@ -108,6 +284,83 @@ impl Encoder {
 
             self.encode_word(word, prev_word, remaining_words, &mut skip_count, result)?;
         }
+        self.chemistry = chemistry;
+        Ok(())
+    }
+
+    /// 단어 사이의 빈칸, 탭, 빈 줄을 묵자 그대로 옮겨 적는다.
+    /// 단어는 공백을 한 칸으로 줄여 적을 때와 똑같이 나누므로 앞뒤 단어를 보는 규칙도 그대로 적용된다.
+    fn encode_preserving(
+        &mut self,
+        text: &str,
+        next_word: Option<&str>,
+        result: &mut Vec<u8>,
+    ) -> Result<(), BraillifyError> {
+        // 공백 문자는 모두 1바이트이므로 바꾸어도 위치가 그대로이다.
+        let normalized = text.replace(['\t', '\n', '\r'], " ");
+        let spans = split_word_spans(&normalized);
+        let mut words = spans
+            .iter()
+            .map(|span| &text[span.clone()])
+            .collect::<Vec<_>>();
+        words.extend(next_word);
+        let chemistry = self.chemistry;
+        self.chemistry |= words
+            .iter()
+            .any(|word| word.contains(chemistry::REACTION_ARROWS));
+
+        let mut end = 0;
+        let mut word: &str = "";
+        for (idx, span) in spans.iter().enumerate() {
+            self.push_whitespace(&text[end..span.start], result);
+            let prev_word = word;
+            word = words[idx];
+            let remaining_words = &words[idx + 1..];
+
+            let mut skip_count = 0;
+
+            self.encode_word(word, prev_word, remaining_words, &mut skip_count, result)?;
+            // 단어 뒤의 한 칸은 원문의 공백으로 다시 적는다.
+            if idx + 1 < spans.len() && result.last() == Some(&0) {
+                result.pop();
+            }
+            end = span.end;
+        }
+        self.push_whitespace(&text[end..], result);
+        self.chemistry = chemistry;
+        Ok(())
+    }
+
+    /// 빈칸은 한 칸, 줄 바꿈은 줄 바꿈으로 적고, 탭은 다음 탭 위치까지 빈칸을 채운다.
+    fn push_whitespace(&self, whitespace: &str, result: &mut Vec<u8>) {
+        let tab_width = self.tab_width.max(1);
+        for c in whitespace.chars() {
+            match c {
+                '\n' => result.push(255),
+                '\t' => {
+                    let column = result.iter().rev().take_while(|c| **c != 255).count();
+                    result.extend(std::iter::repeat_n(0, tab_width - column % tab_width));
+                }
+                '\r' => {}
+                _ => result.push(0),
+            }
+        }
+    }
+
+    /// 수식 뒤에 붙은 조사나 문장 부호를 적고 다음 단어와 띄어 쓴다.
+    fn encode_after_math(
+        &mut self,
+        rest: &str,
+        remaining_words: &[&str],
+        result: &mut Vec<u8>,
+    ) -> Result<(), BraillifyError> {
+        if !rest.is_empty() {
+            self.encode(rest, result)?;
+        }
+        if !remaining_words.is_empty() {
+            result.push(0);
+        }
+        self.has_processed_word = true;
         Ok(())
     }
 
@@ -119,17 +372,69 @@ impl Encoder {
         skip_count: &mut usize,
         result: &mut Vec<u8>,
     ) -> Result<(), BraillifyError> {
-        if word.starts_with('$') && word.ends_with('$') {
-            if let Some((whole, num, den)) = fraction::parse_latex_fraction(word) {
+        // 띄어 쓴 날짜(2024. 10. 17.)는 한 줄에 적는다.
+        if self.date_words > 0 {
+            self.date_words -= 1;
+            if result.last() == Some(&0) {
+                self.joins.push(result.len() - 1);
+            }
+        } else if let Some(len) = numeric::recognize_spaced_date(word, remaining_words) {
+            self.date_words = len - 1;
+        }
+        if let Some((prefix, content, rest)) = braille::split_escape(word) {
+            if !prefix.is_empty() {
+                self.encode(prefix, result)?;
+            }
+            result.extend(braille::encode_escape(content)?);
+            if rest.is_empty() {
+                if !remaining_words.is_empty() {
+                    result.push(0);
+                }
+                return Ok(());
+            }
+            return self.encode_before(rest, remaining_words.first().copied(), result);
+        }
+        if let Some((prefix, span, rest)) = computer::split_computer_span(word) {
+            if !prefix.is_empty() {
+                self.encode(prefix, result)?;
+            }
+            result.extend(computer::encode_computer_span(span)?);
+            return self.encode_after_math(rest, remaining_words, result);
+        }
+        if let Some((math, rest)) = latex::split_math_span(word) {
+            let span = &word[..word.len() - rest.len()];
+            if let Some((whole, num, den)) = fraction::parse_latex_fraction(span) {
                 if let Some(w) = whole {
                     result.extend(fraction::encode_mixed_fraction(&w, &num, &den)?);
                 } else {
                     result.extend(fraction::encode_fraction(&num, &den)?);
                 }
-                return Ok(());
+            } else {
+                result.extend(latex::encode_latex_math(math)?);
+            }
+            return self.encode_after_math(rest, remaining_words, result);
+        }
+        if let Some((prefix, math, rest)) = mathml::split_math_element(word) {
+            if !prefix.is_empty() {
+                self.encode(prefix, result)?;
             }
+            result.extend(mathml::encode_mathml(math)?);
+            return self.encode_after_math(rest, remaining_words, result);
         }
-        if let Some((_, code, rest)) = word_shortcut::split_word_shortcut(word) {
+        if let Some((prefix, formula, rest)) = chemistry::split_formula(word, self.chemistry) {
+            if !prefix.is_empty() {
+                self.encode(prefix, result)?;
+            }
+            result.extend(chemistry::encode_formula(formula)?);
+            return self.encode_after_math(rest, remaining_words, result);
+        }
+        let expanded_word = unit::expand_compatibility_units(word)
+            .map(|expanded| enumerator::expand_roman_numerals(&expanded).unwrap_or(expanded))
+            .or_else(|| enumerator::expand_roman_numerals(word));
+        let word = expanded_word.as_deref().unwrap_or(word);
+        if self.contractions
+            && let Some((_, code, rest)) = word_shortcut::split_word_shortcut(word)
+        {
             result.extend(code);
             if !rest.is_empty() {
                 // Recursively encode the rest using the current encoder state
@@ -139,7 +444,7 @@ impl Encoder {
             let word_chars = word.chars().collect::<Vec<char>>();
             let word_len = word_chars.len();
             // 단어 전체가 대문자인지 확인(타 언어인 경우 반드시 false)
-            let uppercase_stats = word_chars.iter().filter(|c| c.is_ascii_alphabetic()).fold(
+            let uppercase_stats = word_chars.iter().filter(|c| english::is_english_letter(**c)).fold(
                 (0, 0),
                 |(letters, uppers), ch| {
                     (letters + 1, uppers + if ch.is_uppercase() { 1 } else { 0 })
@@ -150,16 +455,16 @@ impl Encoder {
                 .iter()
                 .any(|c| 0xAC00 <= *c as u32 && *c as u32 <= 0xD7A3);
 
-            let has_ascii_alphabetic = word_chars.iter().any(|c| c.is_ascii_alphabetic());
+            let has_ascii_alphabetic = word_chars.iter().any(|c| english::is_english_letter(*c));
             let mut pending_english_start =
                 self.english_indicator && !self.is_english && has_ascii_alphabetic;
-            if pending_english_start && word_chars[0].is_ascii_alphabetic() {
+            if pending_english_start && english::is_english_letter(word_chars[0]) {
                 // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
                 self.enter_english(result);
                 pending_english_start = false;
             }
 
-            let first_ascii_index = word_chars.iter().position(|c| c.is_ascii_alphabetic());
+            let first_ascii_index = word_chars.iter().position(|c| english::is_english_letter(*c));
             let ascii_starts_at_beginning = matches!(first_ascii_index, Some(0));
 
             if is_all_uppercase && !self.triple_big_english && ascii_starts_at_beginning {
@@ -185,14 +490,38 @@ impl Encoder {
             let mut is_number = false;
             let mut is_big_english = false;
 
+            // 제40~44항 시각, 날짜, 전화번호, 범위 등 숫자 표현을 먼저 분류한다.
+            let numeric_spans = numeric::recognize(word);
+            for span in &numeric_spans {
+                if let Some(message) = span.warning {
+                    self.warnings.push(EncodingWarning {
+                        text: span.text.clone(),
+                        message: message.to_string(),
+                    });
+                }
+            }
+
+            let mut syllable_break = None;
+
             for (i, c) in word_chars.iter().enumerate() {
+                let syllable_trim = syllable_break.take();
                 if *skip_count > 0 {
                     *skip_count -= 1;
                     continue;
                 }
 
+                if is_number
+                    && let Some((code, len)) = unit::encode_unit_after_number(&word_chars[i..])
+                {
+                    // 숫자 뒤의 단위에는 로마자표를 적지 않는다.
+                    result.extend(code);
+                    *skip_count = len - 1;
+                    is_number = false;
+                    continue;
+                }
+
                 if pending_english_start
-                    && (c.is_ascii_alphabetic()
+                    && (english::is_english_letter(*c)
                         || (english_logic::should_render_symbol_as_english(
                             self.english_indicator,
                             self.is_english,
@@ -207,11 +536,41 @@ impl Encoder {
                     pending_english_start = false;
                 }
 
-                let char_type = CharType::new(*c)?;
+                // 숫자 표현의 종류에 따라 부호와 지수를 적는다.
+                let is_attached = numeric_spans.iter().any(|s| s.is_attached_symbol(i));
+                if let Some((code, len)) = numeric::encode_exponent_sign(&word_chars[i..]) {
+                    result.extend(code);
+                    *skip_count = len - 1;
+                    continue;
+                }
+
+                let char_type = if *c == '-'
+                    && (is_attached
+                        || (!numeric_spans.iter().any(|s| s.contains(i) && s.uses_hyphen())
+                            && math_symbol_shortcut::is_minus_sign(
+                                &word_chars,
+                                i,
+                                prev_word,
+                                remaining_words,
+                            )))
+                {
+                    CharType::MathSymbol('−')
+                } else {
+                    CharType::new(*c)?
+                };
+
+                if self.is_japanese
+                    && !matches!(
+                        char_type,
+                        CharType::Japanese(_) | CharType::Number(_) | CharType::Braille(_)
+                    )
+                {
+                    self.exit_japanese(result);
+                }
 
                 if self.english_indicator && self.is_english {
                     match &char_type {
-                        CharType::English(_) => {}
+                        CharType::English(_) | CharType::Braille(_) => {}
                         CharType::Number(_) => {
                             // 제35항 로마자와 숫자가 이어 나올 때에는 로마자 종료표를 적지 않는다.
                             // 숫자 뒤에 로마자가 이어질 경우 연속표가 필요하므로 종료표 대신
@@ -246,17 +605,46 @@ impl Encoder {
                     }
                 }
 
+                if !self.is_english
+                    && let Some((trim, mark)) =
+                        line::break_before(&word_chars, i, is_number, syllable_trim)
+                {
+                    self.breaks.push(BreakPoint {
+                        position: result.len(),
+                        trim,
+                        mark,
+                    });
+                }
+
                 match char_type {
                     CharType::Korean(korean) => {
                         self.needs_english_continuation = false;
-                        if is_number
-                            && (['ㄴ', 'ㄷ', 'ㅁ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'].contains(&korean.cho)
-                                || *c == '운')
-                        {
-                            // 44항 [다만] 숫자와 혼동되는 ‘ㄴ, ㄷ, ㅁ, ㅋ, ㅌ, ㅍ, ㅎ’의 첫소리 글자와 ‘운’의 약자는 숫자 뒤에 붙어 나오더라도 숫자와 한글을 띄어 쓴다.
-                            result.push(0);
+                        if i > 0 && !utils::is_korean_char(word_chars[i - 1]) {
+                            // 숫자, 로마자, 기호 뒤에 붙어 나온 한글을 띄어 쓸지 정한다. (제44항 [다만] 등)
+                            // 연산 기호 뒤의 띄어쓰기는 연산 기호를 적을 때 정한다.
+                            let prev = word_chars[i - 1];
+                            let preceding = if is_number {
+                                Some(Preceding::Number)
+                            } else if english::is_english_letter(prev) {
+                                Some(Preceding::English)
+                            } else if math_symbol_shortcut::is_spaced_operator(prev) {
+                                None
+                            } else {
+                                Some(Preceding::Symbol)
+                            };
+                            let korean_run = word_chars[i..]
+                                .iter()
+                                .take_while(|c| utils::is_korean_char(**c))
+                                .collect::<String>();
+                            if let Some(preceding) = preceding
+                                && particle::needs_space_before(preceding, &korean_run)
+                            {
+                                result.push(0);
+                            }
                         }
 
+                        // 줄을 바꿀 때 윗줄 끝에서 지우는 칸 수
+                        let mut trim = 0;
                         // "겄"의 경우 4항으로 해석해야 하지만 "것 + ㅅ" 으로 해석될 여지가 있으므로 예외처리
                         if ['팠', '껐', '셩', '쎵', '졍', '쪙', '쳥', '겄'].contains(c) {
                             // 14항 [붙임] "팠"을 적을 때에는 "ㅏ"를 생략하지 않고 적는다.
@@ -277,15 +665,21 @@ impl Encoder {
                             // 14항 ‘나, 다, 마, 바, 자, 카, 타, 파, 하’에 모음이 붙어 나올 때에는 약자를 사용하지 않는다
                             result.push(encode_choseong(korean.cho)?);
                             result.extend(encode_jungsong(korean.jung)?);
-                        } else {
+                            trim = 1;
+                        } else if self.contractions {
                             result.extend(encode_korean_char(&korean)?);
+                        } else {
+                            result.extend(encode_korean_char_uncontracted(&korean)?);
                         }
 
                         if i < word_len - 1 {
+                            let len = result.len();
                             // 11 - 모음자에 ‘예’가 붙어 나올 때에는 그 사이에 구분표 -을 적어 나타낸다
                             rule_11(&korean, word_chars[i + 1], result)?;
                             rule_12(&korean, word_chars[i + 1], result)?;
+                            trim += result.len() - len;
                         }
+                        syllable_break = Some(trim);
                     }
                     CharType::KoreanPart(c) => {
                         self.needs_english_continuation = false;
@@ -361,14 +755,16 @@ impl Encoder {
                             }
                         }
                         if !self.is_english || i == 0 {
-                            if !is_all_uppercase
+                            if self.contractions
+                                && !is_all_uppercase
                                 && let Some((code, len)) = rule_en_10_6(
                                     &word_chars[i..].iter().collect::<String>().to_lowercase(),
                                 )
                             {
                                 result.push(code);
                                 *skip_count = len;
-                            } else if !is_all_uppercase
+                            } else if self.contractions
+                                && !is_all_uppercase
                                 && let Some((code, len)) = rule_en_10_4(
                                     &word_chars[i..].iter().collect::<String>().to_lowercase(),
                                 )
@@ -376,15 +772,17 @@ impl Encoder {
                                 result.push(code);
                                 *skip_count = len;
                             } else {
-                                result.push(english::encode_english(c)?);
+                                result.extend(english::encode_english_letter(c)?);
                             }
-                        } else if let Some((code, len)) =
-                            rule_en_10_4(&word_chars[i..].iter().collect::<String>().to_lowercase())
+                        } else if self.contractions
+                            && let Some((code, len)) = rule_en_10_4(
+                                &word_chars[i..].iter().collect::<String>().to_lowercase(),
+                            )
                         {
                             result.push(code);
                             *skip_count = len;
                         } else {
-                            result.push(english::encode_english(c)?);
+                            result.extend(english::encode_english_letter(c)?);
                         }
                         self.is_english = true;
                         self.needs_english_continuation = false;
@@ -518,28 +916,62 @@ impl Encoder {
                             }
                         }
                     }
+                    CharType::Unit(c) => {
+                        // 화폐 기호는 숫자 앞에, 백분율·온도 기호는 숫자 뒤에 붙여 쓴다.
+                        result.extend(unit::encode_unit_sign(c)?);
+                    }
+                    CharType::Enumerator(c) => {
+                        result.extend(enumerator::encode_enumerator(c)?);
+                    }
+                    CharType::Japanese(kana) => {
+                        if !self.is_japanese {
+                            // 외국어 점자 일람표 - 국어 문장 안의 일본어 앞에는 일본어 표시를 적는다.
+                            self.enter_japanese(result);
+                        }
+                        let (code, len) = japanese::encode_japanese(
+                            kana,
+                            word_chars.get(i + 1).copied(),
+                            is_number,
+                        )?;
+                        result.extend(code);
+                        *skip_count = len - 1;
+                    }
                     CharType::Space(c) => {
                         result.push(if c == '\n' { 255 } else { 0 });
                     }
+                    CharType::Braille(c) => {
+                        // 점자 패턴 문자는 그대로 적고, 로마자나 숫자 상태는 건드리지 않는다.
+                        result.push(braille::encode_braille_char(c)?);
+                        continue;
+                    }
                     CharType::MathSymbol(c) => {
-                        if i > 0 && word_chars[..i].iter().any(|c| utils::is_korean_char(*c)) {
-                            result.push(0);
-                        }
-                        result.extend(math_symbol_shortcut::encode_char_math_symbol_shortcut(c)?);
-                        if i < word_len - 1 {
-                            let mut korean = vec![];
-                            for wc in word_chars[i..].iter() {
-                                if utils::is_korean_char(*wc) {
-                                    korean.push(*wc);
-                                } else if !korean.is_empty() {
-                                    break;
-                                }
+                        if let Some((code, len)) =
+                            math_symbol_shortcut::encode_script_digits(&word_chars[i..])
+                        {
+                            result.extend(code);
+                            *skip_count = len - 1;
+                        } else if is_attached || !math_symbol_shortcut::is_spaced_operator(c) {
+                            result.extend(math_symbol_shortcut::encode_char_math_symbol_shortcut(c)?);
+                        } else {
+                            // 제46항 연산 기호가 한글 사이에 올 때에는 앞뒤를 띄어 쓴다.
+                            if i > 0 && word_chars[..i].iter().any(|c| utils::is_korean_char(*c)) {
+                                result.push(0);
                             }
-                            if !korean.is_empty() {
-                                // 조사일 경우, 수 뒤에 올 경우 구분하는 것으로 판단
-                                if !["과", "와", "이다", "하고", "이랑", "와", "랑", "아니다"]
-                                    .contains(&korean.iter().collect::<String>().as_str())
-                                {
+                            result.extend(math_symbol_shortcut::encode_char_math_symbol_shortcut(c)?);
+                            if i < word_len - 1 {
+                                let mut korean = vec![];
+                                for wc in word_chars[i..].iter() {
+                                    if utils::is_korean_char(*wc) {
+                                        korean.push(*wc);
+                                    } else if !korean.is_empty() {
+                                        break;
+                                    }
+                                }
+                                // 연산 기호 뒤의 한글이 조사이면 붙여 쓰고, 낱말이면 띄어 쓴다.
+                                if particle::needs_space_before(
+                                    Preceding::Operator,
+                                    &korean.iter().collect::<String>(),
+                                ) {
                                     result.push(0);
                                 }
                             }
@@ -549,7 +981,7 @@ impl Encoder {
                 if !c.is_numeric() {
                     is_number = false;
                 }
-                if c.is_ascii_alphabetic() && !c.is_uppercase() {
+                if english::is_english_letter(*c) && !c.is_uppercase() {
                     is_big_english = false;
                 }
             }
@@ -572,10 +1004,10 @@ impl Encoder {
                 if let Some(next_word) = remaining_words.first() {
                     let ascii_letters = next_word
                         .chars()
-                        .filter(|c| c.is_ascii_alphabetic())
+                        .filter(|c| english::is_english_letter(*c))
                         .collect::<Vec<_>>();
                     let has_invalid_symbol = next_word.chars().any(|ch| {
-                        !(ch.is_ascii_alphabetic()
+                        !(english::is_english_letter(ch)
                             || english_logic::is_english_symbol(ch)
                             || symbol_shortcut::is_symbol_char(ch)
                             || utils::is_korean_char(ch))
@@ -627,6 +1059,15 @@ impl Encoder {
                 }
             }
 
+            if self.is_japanese
+                && !remaining_words
+                    .first()
+                    .and_then(|w| w.chars().next())
+                    .is_some_and(|c| japanese::is_japanese_char(c) || c.is_ascii_digit())
+            {
+                self.exit_japanese(result);
+            }
+
             result.push(0);
         }
 
@@ -648,12 +1089,96 @@ impl Encoder {
     }
 }
 
+/// 공백으로 단어를 나누되, `$…$` 수식과 `<math>…</math>`, 백틱으로 감싼 코드, `[[BR: …]]` 안의 공백에서는 나누지 않는다.
+/// 여는 `$` 바로 뒤와 닫는 `$` 바로 앞에는 공백이 없어야 수식으로 본다.
+fn split_word_spans(text: &str) -> Vec<Range<usize>> {
+    let mut pieces = vec![];
+    let mut offset = 0;
+    for piece in text.split(' ') {
+        pieces.push((offset, piece));
+        offset += piece.len() + 1;
+    }
+
+    let mut words = vec![];
+    let mut i = 0;
+    while i < pieces.len() {
+        let (start, piece) = pieces[i];
+        i += 1;
+        if piece.is_empty() {
+            continue;
+        }
+        if piece.len() > 1 && piece.starts_with('$') && piece.matches('$').count() == 1 {
+            let close = pieces[i..].iter().position(|(_, p)| {
+                p.find('$').is_some_and(|idx| idx > 0)
+            });
+            if let Some(close) = close {
+                let (end_start, end_piece) = pieces[i + close];
+                words.push(start..end_start + end_piece.len());
+                i += close + 1;
+                continue;
+            }
+        }
+        if piece.contains("[[BR:") && !piece.contains("]]") {
+            let close = pieces[i..].iter().position(|(_, p)| p.contains("]]"));
+            if let Some(close) = close {
+                let (end_start, end_piece) = pieces[i + close];
+                words.push(start..end_start + end_piece.len());
+                i += close + 1;
+                continue;
+            }
+        }
+        if piece.matches('`').count() == 1 {
+            let close = pieces[i..].iter().position(|(_, p)| p.contains('`'));
+            if let Some(close) = close {
+                let (end_start, end_piece) = pieces[i + close];
+                words.push(start..end_start + end_piece.len());
+                i += close + 1;
+                continue;
+            }
+        }
+        if let Some(open) = piece.find("<math")
+            && !piece[open..].contains("</math>")
+        {
+            let close = pieces[i..]
+                .iter()
+                .position(|(_, p)| p.contains("</math>"));
+            if let Some(close) = close {
+                let (end_start, end_piece) = pieces[i + close];
+                words.push(start..end_start + end_piece.len());
+                i += close + 1;
+                continue;
+            }
+        }
+        words.push(start..start + piece.len());
+    }
+    words
+}
+
+fn split_words(text: &str) -> Vec<&str> {
+    split_word_spans(text)
+        .into_iter()
+        .map(|span| &text[span])
+        .collect()
+}
+
 pub fn encode(text: &str) -> Result<Vec<u8>, BraillifyError> {
     let config = EncodingConfig::default();
     encode_with_config(text, config)
 }
 
 pub fn encode_with_config(text: &str, config: EncodingConfig) -> Result<Vec<u8>, BraillifyError> {
+    encode_with_config_and_warnings(text, config).map(|(result, _)| result)
+}
+
+fn encode_with_config_and_warnings(
+    text: &str,
+    config: EncodingConfig,
+) -> Result<(Vec<u8>, Vec<EncodingWarning>), BraillifyError> {
+    let (result, mut encoder) = run_encoder(text, config)?;
+    Ok((result, encoder.take_warnings()))
+}
+
+fn run_encoder(text: &str, config: EncodingConfig) -> Result<(Vec<u8>, Encoder), BraillifyError> {
     let mut final_english_indicator = config.english_indicator;
     if final_english_indicator {
         final_english_indicator = text
@@ -670,10 +1195,30 @@ pub fn encode_with_config(text: &str, config: EncodingConfig) -> Result<Vec<u8>,
     }
     
     let mut encoder = Encoder::new(final_english_indicator);
+    encoder.whitespace = config.whitespace;
+    encoder.tab_width = config.tab_width;
+    encoder.chemistry = config.chemistry;
     let mut result = Vec::new();
     encoder.encode(text, &mut result)?;
     encoder.finish(&mut result)?;
-    Ok(result)
+    Ok((result, encoder))
+}
+
+/// 점자와 함께 단어 안에서 줄을 바꿀 수 있는 자리를 돌려준다. `break_lines`로 줄을 나눌 때 쓴다.
+pub fn encode_with_breaks(text: &str, config: EncodingConfig) -> Result<Encoded, BraillifyError> {
+    let (cells, encoder) = run_encoder(text, config)?;
+    Ok(Encoded {
+        cells,
+        breaks: encoder.breaks,
+        joins: encoder.joins,
+    })
+}
+
+/// 점자와 함께 분류가 확실하지 않았던 부분에 대한 경고를 돌려준다.
+pub fn encode_with_warnings(
+    text: &str,
+) -> Result<(Vec<u8>, Vec<EncodingWarning>), BraillifyError> {
+    encode_with_config_and_warnings(text, EncodingConfig::default())
 }
 
 pub fn encode_to_unicode(text: &str) -> Result<String, BraillifyError> {
@@ -684,6 +1229,15 @@ pub fn encode_to_unicode(text: &str) -> Result<String, BraillifyError> {
         .collect::<String>())
 }
 
+/// 북미 점자 ASCII(BRF)로 적는다.
+pub fn encode_to_braille_ascii(text: &str) -> Result<String, BraillifyError> {
+    let result = encode(text)?;
+    result
+        .iter()
+        .map(|c| unicode::encode_braille_ascii(*c))
+        .collect()
+}
+
 pub fn encode_to_braille_font(text: &str) -> Result<String, BraillifyError> {
     let result = encode(text)?;
     Ok(result
@@ -700,6 +1254,50 @@ mod test {
     use proptest::prelude::*;
 
     use super::*;
+    #[test]
+    pub fn test_encode_with_warnings() {
+        let (_, warnings) = encode_with_warnings("1-2번 문제").unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].text, "1-2");
+        let (_, warnings) = encode_with_warnings("12:30에 만나요").unwrap();
+        assert!(warnings.is_empty());
+        let (_, warnings) = encode_with_warnings("5-3=2").unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    pub fn test_encode_whitespace_preserve() {
+        let config = EncodingConfig {
+            whitespace: WhitespaceMode::Preserve,
+            tab_width: 4,
+            ..Default::default()
+        };
+        let to_unicode = |text: &str| {
+            encode_with_config(text, config.clone())
+                .unwrap()
+                .iter()
+                .map(|c| encode_unicode(*c))
+                .collect::<String>()
+        };
+        assert_eq!(to_unicode("  가   나 "), "⠀⠀⠫⠀⠀⠀⠉⠀");
+        assert_eq!(to_unicode("가\t나\n\t다"), "⠫⠀⠀⠀⠉\n⠀⠀⠀⠀⠊");
+        assert_eq!(to_unicode("가\r\n\n\n나"), "⠫\n\n\n⠉");
+        // 띄어쓰기가 달라도 앞뒤 단어를 보는 규칙은 그대로 적용된다.
+        for text in ["사과는 apple 이다.", "1  2", "그래서  ABC\tDEF를"] {
+            assert_eq!(
+                to_unicode(text).replace('⠀', ""),
+                encode_to_unicode(&text.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .unwrap()
+                    .replace('⠀', ""),
+                "{text}"
+            );
+        }
+        assert_eq!(
+            encode_to_unicode("가   나").unwrap(),
+            encode_to_unicode("가 나").unwrap()
+        );
+    }
+
     #[test]
     pub fn test_encode() {
         assert_eq!(encode_to_unicode("상상이상의 ").unwrap(), "⠇⠶⠇⠶⠕⠇⠶⠺");
@@ -811,8 +1409,114 @@ mod test {
         );
         assert_eq!(encode_to_unicode("$\\frac{3}{4}$").unwrap(), "⠼⠙⠌⠼⠉");
         assert_eq!(encode_to_unicode("$3\\frac{1}{4}$").unwrap(), "⠼⠉⠼⠙⠌⠼⠁");
+        assert_eq!(
+            encode_to_unicode("$x^2 + y^2 = r^2$").unwrap(),
+            "⠭⠘⠼⠃⠢⠽⠘⠼⠃⠒⠒⠗⠘⠼⠃"
+        );
+        assert_eq!(
+            encode_to_unicode("원의 넓이는 $\\pi r^2$이다.").unwrap(),
+            "⠏⠒⠺⠀⠉⠞⠃⠕⠉⠵⠀⠨⠏⠗⠘⠼⠃⠕⠊⠲"
+        );
+        assert_eq!(encode_to_unicode("$\\frac{1}{2}$ 입니다").unwrap(), "⠼⠃⠌⠼⠁⠀⠕⠃⠉⠕⠊");
+        assert!(encode_to_unicode("$\\foo{x}$").is_err());
+        assert_eq!(
+            encode_to_unicode(
+                "답은 <math><mfrac><mn>1</mn><mn>2</mn></mfrac></math>이다."
+            )
+            .unwrap(),
+            "⠊⠃⠵⠀⠼⠃⠌⠼⠁⠕⠊⠲"
+        );
+        assert_eq!(
+            encode_to_unicode("<math> <mi>x</mi> <mo>=</mo> <mn>1</mn> </math>").unwrap(),
+            "⠭⠒⠒⠼⠁"
+        );
         assert_eq!(encode_to_unicode("1/2").unwrap(), "⠼⠁⠸⠌⠼⠃");
+        assert_eq!(encode_to_unicode("3+4는 7이다.").unwrap(), "⠼⠉⠢⠼⠙⠀⠉⠵⠀⠼⠛⠕⠊⠲");
+        assert_eq!(encode_to_unicode("3+4에서").unwrap(), "⠼⠉⠢⠼⠙⠝⠠⠎");
+        assert_eq!(encode_to_unicode("값=넓이").unwrap(), "⠫⠃⠄⠀⠒⠒⠀⠉⠞⠃⠕");
+        assert_eq!(
+            encode_to_unicode("+82-10-1234-5678").unwrap(),
+            "⠢⠼⠓⠃⠤⠼⠁⠚⠤⠼⠁⠃⠉⠙⠤⠼⠑⠋⠛⠓"
+        );
+        assert_eq!(encode_to_unicode("2024-10-17").unwrap(), "⠼⠃⠚⠃⠙⠤⠼⠁⠚⠤⠼⠁⠛");
+        assert_eq!(encode_to_unicode("09:00~18:00").unwrap(), "⠼⠚⠊⠐⠂⠼⠚⠚⠈⠔⠼⠁⠓⠐⠂⠼⠚⠚");
+        assert_eq!(encode_to_unicode("3.2×10⁵").unwrap(), "⠼⠉⠲⠃⠡⠼⠁⠚⠘⠼⠑");
+        assert_eq!(encode_to_unicode("1.5×10⁻³").unwrap(), "⠼⠁⠲⠑⠡⠼⠁⠚⠘⠔⠼⠉");
+        assert_eq!(encode_to_unicode("1.5×10⁻").unwrap(), "⠼⠁⠲⠑⠡⠼⠁⠚⠘⠔");
+        assert_eq!(encode_to_unicode("10⁻³").unwrap(), "⠼⠁⠚⠘⠔⠼⠉");
+        assert_eq!(encode_to_unicode("-5개").unwrap(), "⠔⠼⠑⠈⠗");
+        assert_eq!(encode_to_unicode("-10~-5").unwrap(), "⠔⠼⠁⠚⠈⠔⠔⠼⠑");
+        assert_eq!(encode_to_unicode("-3").unwrap(), "⠔⠼⠉");
+        assert_eq!(encode_to_unicode("5-3=2").unwrap(), "⠼⠑⠔⠼⠉⠒⠒⠼⠃");
+        assert_eq!(encode_to_unicode("x-1").unwrap(), "⠭⠔⠼⠁");
+        assert_eq!(encode_to_unicode("a-b=c").unwrap(), "⠁⠔⠃⠒⠒⠉");
+        assert_eq!(encode_to_unicode("A-B").unwrap(), "⠠⠠⠁⠤⠃");
+        assert_eq!(encode_to_unicode("5개").unwrap(), "⠼⠑⠈⠗");
+        assert_eq!(encode_to_unicode("5도로").unwrap(), "⠼⠑⠀⠊⠥⠐⠥");
+        assert_eq!(encode_to_unicode("A4용지").unwrap(), "⠴⠠⠁⠼⠙⠬⠶⠨⠕");
+        assert_eq!(encode_to_unicode("x+y가").unwrap(), "⠴⠭⠲⠢⠴⠽⠲⠫");
+        assert_eq!(encode_to_unicode("x+y도로").unwrap(), "⠴⠭⠲⠢⠀⠴⠽⠲⠊⠥⠐⠥");
+        assert_eq!(encode_to_unicode("x+y가지").unwrap(), "⠴⠭⠲⠢⠀⠴⠽⠲⠫⠨⠕");
+        assert_eq!(encode_to_unicode("5 - 3 = 2").unwrap(), "⠼⠑⠀⠔⠀⠼⠉⠀⠒⠒⠀⠼⠃");
+        assert_eq!(encode_to_unicode("3²=9").unwrap(), "⠼⠉⠘⠼⠃⠒⠒⠼⠊");
+        assert_eq!(encode_to_unicode("각도는 30°이다.").unwrap(), "⠫⠁⠊⠥⠉⠵⠀⠼⠉⠚⠘⠚⠕⠊⠲");
+        assert_eq!(encode_to_unicode("∴ a∥b").unwrap(), "⠠⠡⠀⠁⠸⠇⠃");
         assert_eq!(encode_to_unicode("½").unwrap(), "⠼⠃⠌⠼⠁");
+        assert_eq!(encode_to_unicode("① 사과").unwrap(), "⠸⠴⠼⠁⠇⠀⠇⠈⠧");
+        assert_eq!(encode_to_unicode("⑴ 배").unwrap(), "⠦⠄⠼⠁⠠⠴⠀⠘⠗");
+        assert_eq!(encode_to_unicode("㉠ 항목").unwrap(), "⠸⠴⠿⠁⠇⠀⠚⠶⠑⠭");
+        assert_eq!(encode_to_unicode("Ⅳ. 결론").unwrap(), "⠴⠠⠠⠊⠧⠲⠀⠈⠳⠐⠷");
+        assert_eq!(encode_to_unicode("물(H₂O)은").unwrap(), "⠑⠯⠦⠄⠠⠓⠰⠼⠃⠠⠕⠠⠴⠵");
+        assert_eq!(
+            encode_to_unicode("2H₂ + O₂ → 2H₂O").unwrap(),
+            "⠼⠃⠠⠓⠰⠼⠃⠀⠢⠀⠠⠕⠰⠼⠃⠀⠒⠒⠕⠀⠼⠃⠠⠓⠰⠼⠃⠠⠕"
+        );
+        assert_eq!(encode_to_unicode("SO₄²⁻").unwrap(), "⠠⠎⠠⠕⠰⠼⠙⠘⠼⠃⠔");
+        assert_eq!(encode_to_unicode("PS5 기종").unwrap(), "⠴⠠⠠⠏⠎⠼⠑⠀⠈⠕⠨⠿");
+        let chemistry = EncodingConfig {
+            chemistry: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            encode_with_config("소금(NaCl)은", chemistry)
+                .unwrap()
+                .iter()
+                .map(|c| unicode::encode_unicode(*c))
+                .collect::<String>(),
+            "⠠⠥⠈⠪⠢⠦⠄⠠⠝⠁⠠⠉⠇⠠⠴⠵"
+        );
+        assert_eq!(encode_to_unicode("abc⠿def").unwrap(), "⠁⠃⠉⠿⠙⠑⠋");
+        assert_eq!(encode_to_unicode("12⠿34").unwrap(), "⠼⠁⠃⠿⠉⠙");
+        assert!(encode_to_unicode("abc⣿def").is_err());
+        assert!(encode_to_unicode("[[BR: 12345678]]").is_err());
+        assert!(encode_to_braille_ascii("[[BR: 178]]").is_err());
+        assert_eq!(
+            encode_to_unicode("표[[BR: 123 0 456]]는").unwrap(),
+            "⠙⠬⠇⠀⠸⠉⠵"
+        );
+        assert_eq!(
+            encode_to_unicode("https://example.com에서").unwrap(),
+            "⠠⠶⠓⠞⠞⠏⠎⠱⠌⠌⠑⠭⠁⠍⠏⠇⠑⠨⠉⠕⠍⠶⠄⠝⠠⠎"
+        );
+        assert_eq!(
+            encode_to_unicode("`ls -al | grep rs`를 입력").unwrap(),
+            "⠠⠶⠇⠎⠀⠤⠁⠇⠀⠠⠳⠀⠛⠗⠑⠏⠀⠗⠎⠶⠄⠐⠮⠀⠕⠃⠐⠱⠁"
+        );
+        assert_eq!(encode_to_unicode("café").unwrap(), "⠉⠁⠋⠘⠌⠑");
+        assert_eq!(encode_to_unicode("naïve").unwrap(), "⠝⠁⠘⠒⠊⠧⠑");
+        assert_eq!(encode_to_unicode("Müller").unwrap(), "⠠⠍⠘⠒⠥⠇⠇⠻");
+        assert_eq!(
+            encode_to_unicode("São Paulo").unwrap(),
+            "⠠⠎⠘⠻⠁⠕⠀⠠⠏⠁⠥⠇⠕"
+        );
+        assert_eq!(
+            encode_to_unicode("카페 café").unwrap(),
+            "⠋⠙⠝⠀⠴⠉⠁⠋⠘⠌⠑"
+        );
+        assert_eq!(encode_to_unicode("Émile").unwrap(), "⠠⠘⠌⠑⠍⠊⠇⠑");
+        assert_eq!(encode_to_unicode("Erdős").unwrap(), "⠠⠻⠙⠘⠌⠘⠌⠕⠎");
+        assert_eq!(encode_to_unicode("żółw").unwrap(), "⠘⠆⠵⠘⠌⠕⠈⠡⠇⠺");
+        assert_eq!(encode_to_unicode("Iğdır").unwrap(), "⠠⠊⠈⠬⠛⠙⠈⠊⠗");
     }
 
     #[test]
@@ -1183,6 +1887,21 @@ mod test {
         }
     }
 
+    #[test]
+    pub fn test_insert_note() {
+        let mut encoder = Encoder::new(true);
+        let mut result = vec![];
+        encoder.encode("사진은 BMI", &mut result).unwrap();
+        encoder.insert_note("표 1 참고", &mut result).unwrap();
+        result.push(0);
+        encoder.encode("BMI 지수", &mut result).unwrap();
+        encoder.finish(&mut result).unwrap();
+        assert_eq!(
+            result.iter().map(|c| encode_unicode(*c)).collect::<String>(),
+            "⠇⠨⠟⠵⠀⠴⠠⠠⠃⠍⠊⠲⠀⠸⠦⠙⠬⠀⠼⠁⠀⠰⠣⠢⠈⠥⠴⠇⠀⠴⠠⠠⠃⠍⠊⠲⠀⠨⠕⠠⠍"
+        );
+    }
+
     #[test]
     fn test_encoder_streaming() {
         // Test encoder can be reused
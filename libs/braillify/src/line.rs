@@ -0,0 +1,380 @@
+use crate::unicode::decode_unicode;
+
+/// 제42항 긴 숫자를 두 줄에 나누어 적을 때 위 줄 끝에 적는 연결표
+const CONTINUATION: u8 = decode_unicode('⠠');
+
+/// 제55항 줄의 끝이나 첫머리에 올 수 있는 빗금과 줄표
+const SLASH_OR_DASH: [char; 3] = ['/', '―', '—'];
+
+/// 단어 안에서 줄을 바꿀 수 있는 자리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BreakPoint {
+    /// 아랫줄이 시작되는 칸의 위치
+    pub position: usize,
+    /// 이 자리에서 줄을 바꿀 때 윗줄 끝에서 지우는 칸 수 (제11항, 제12항, 제14항 다만)
+    pub trim: usize,
+    /// 이 자리에서 줄을 바꿀 때 윗줄 끝에 덧붙이는 칸 (제42항 연결표)
+    pub mark: Option<u8>,
+}
+
+/// 점자와 그 안에서 줄을 바꿀 수 있는 자리
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Encoded {
+    pub cells: Vec<u8>,
+    pub breaks: Vec<BreakPoint>,
+    /// 줄을 바꾸지 않는 빈칸의 위치 (띄어 쓴 날짜 등)
+    pub joins: Vec<usize>,
+}
+
+impl From<Vec<u8>> for Encoded {
+    /// 줄 바꿈 자리를 모르는 점자는 띄어쓰기에서만 줄을 바꾼다.
+    fn from(cells: Vec<u8>) -> Self {
+        Self {
+            cells,
+            breaks: vec![],
+            joins: vec![],
+        }
+    }
+}
+
+fn is_syllable(c: char) -> bool {
+    ('가'..='힣').contains(&c)
+}
+
+/// 제42항 일곱 자리 이상의 숫자이고, 나눈 뒤 아랫줄에 세 자리 이상이 남아야 한다.
+fn can_split_number(chars: &[char], i: usize) -> bool {
+    let is_part = |c: &&char| c.is_ascii_digit() || **c == ',';
+    let before = chars[..i]
+        .iter()
+        .rev()
+        .take_while(is_part)
+        .filter(|c| c.is_ascii_digit())
+        .count();
+    let after = chars[i..]
+        .iter()
+        .take_while(is_part)
+        .filter(|c| c.is_ascii_digit())
+        .count();
+    before + after >= 7 && after >= 3
+}
+
+/// 단어의 `i`번째 글자 앞에서 줄을 바꿀 수 있으면 (지우는 칸 수, 덧붙이는 칸)을 돌려준다.
+/// `syllable_trim`은 바로 앞 글자가 한글 음절일 때 그 뒤에 적은 구분표나 생략한 모음의 칸 수이다.
+pub(crate) fn break_before(
+    chars: &[char],
+    i: usize,
+    is_number: bool,
+    syllable_trim: Option<usize>,
+) -> Option<(usize, Option<u8>)> {
+    if i == 0 {
+        return None;
+    }
+    let (prev, c) = (chars[i - 1], chars[i]);
+    if let Some(trim) = syllable_trim
+        && is_syllable(c)
+    {
+        // 음절과 음절 사이. 구분표는 적지 않고(제11항, 제12항 다만) 약자를 쓴다(제14항 다만).
+        return Some((trim, None));
+    }
+    if is_number && prev.is_ascii_digit() && c.is_ascii_digit() && can_split_number(chars, i) {
+        return Some((0, Some(CONTINUATION)));
+    }
+    if prev == '/' && c == '/' {
+        return None;
+    }
+    // 제50항 가운뎃점은 줄 끝에만, 제55항 빗금과 줄표는 줄의 끝이나 첫머리에 올 수 있다.
+    if prev == '·' || SLASH_OR_DASH.contains(&prev) || SLASH_OR_DASH.contains(&c) {
+        return Some((0, None));
+    }
+    None
+}
+
+/// 문단 첫 줄과 나머지 줄을 들여 쓰는 칸 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Indent {
+    pub first: usize,
+    pub runover: usize,
+}
+
+/// 줄 앞에 들여쓰기 칸을 채워 넣는다. 빈 줄에는 빈칸을 적지 않는다.
+fn push_line(lines: &mut Vec<Vec<u8>>, line: Vec<u8>, margin: usize) {
+    if line.is_empty() {
+        lines.push(line);
+        return;
+    }
+    let mut indented = vec![0; margin];
+    indented.extend(line);
+    lines.push(indented);
+}
+
+/// 한 문단을 줄로 나눈다. 문단 첫머리의 들여쓰기는 그대로 둔다.
+fn break_paragraph(
+    cells: &[u8],
+    breaks: &[BreakPoint],
+    joins: &[usize],
+    line_width: usize,
+    indent: Indent,
+    lines: &mut Vec<Vec<u8>>,
+) {
+    let first_line = lines.len();
+    let margin = |lines: &Vec<Vec<u8>>| {
+        if lines.len() == first_line {
+            indent.first
+        } else {
+            indent.runover
+        }
+    };
+    let mut line = vec![];
+    let mut wrapped = false;
+    let mut i = 0;
+    while i < cells.len() {
+        let gap_start = i;
+        while i < cells.len() && cells[i] == 0 {
+            i += 1;
+        }
+        if i == cells.len() {
+            break;
+        }
+        let mut gap = if line.is_empty() && wrapped {
+            0
+        } else {
+            i - gap_start
+        };
+        let mut start = i;
+        // 이어 적는 빈칸은 단어의 일부로 본다.
+        while i < cells.len()
+            && (cells[i] != 0 || (joins.contains(&i) && cells.get(i + 1).is_some_and(|c| *c != 0)))
+        {
+            i += 1;
+        }
+        let end = i;
+
+        while start < end {
+            let margin = margin(lines);
+            let width = line_width.saturating_sub(margin).max(1);
+            let used = line.len() + gap;
+            if used + end - start <= width {
+                line.resize(used, 0);
+                line.extend(&cells[start..end]);
+                break;
+            }
+            if used > 0 && end - start <= width {
+                // 단어를 나누지 않고 다음 줄로 넘긴다.
+                if !line.is_empty() {
+                    push_line(lines, std::mem::take(&mut line), margin);
+                }
+                wrapped = true;
+                gap = 0;
+                continue;
+            }
+            // 한 줄보다 긴 단어는 남은 칸에 들어가는 마지막 자리에서 나눈다.
+            let room = width.saturating_sub(used);
+            let point = breaks
+                .iter()
+                .filter(|point| start < point.position && point.position < end)
+                .rfind(|point| {
+                    point.position - start - point.trim.min(point.position - start)
+                        + usize::from(point.mark.is_some())
+                        <= room
+                });
+            if let Some(point) = point {
+                line.resize(used, 0);
+                line.extend(&cells[start..point.position - point.trim]);
+                line.extend(point.mark);
+                start = point.position;
+            } else if used > 0 {
+                if !line.is_empty() {
+                    push_line(lines, std::mem::take(&mut line), margin);
+                }
+                wrapped = true;
+                gap = 0;
+                continue;
+            } else {
+                // 나눌 수 있는 자리가 없으면 줄 너비에서 자른다.
+                line.extend(&cells[start..start + width]);
+                start += width;
+            }
+            push_line(lines, std::mem::take(&mut line), margin);
+            wrapped = true;
+            gap = 0;
+        }
+    }
+    let margin = margin(lines);
+    push_line(lines, line, margin);
+}
+
+/// 점자를 한 줄에 `width`칸씩 나눈다. 띄어쓰기에서 줄을 바꾸는 것을 먼저 하고,
+/// 한 줄보다 긴 단어만 규정이 허용하는 자리에서 나눈다.
+/// 로마자 구간과 묶음 기호 안은 나누지 않으며, 긴 숫자를 나눈 아랫줄에는 수표를 다시 적지 않는다(제42항).
+pub fn break_lines(encoded: &Encoded, width: usize) -> Vec<Vec<u8>> {
+    break_lines_indented(encoded, width, Indent::default())
+}
+
+/// 문단마다 첫 줄과 나머지 줄을 `indent`만큼 들여 쓰며 줄을 나눈다.
+pub fn break_lines_indented(encoded: &Encoded, width: usize, indent: Indent) -> Vec<Vec<u8>> {
+    let width = width.max(1);
+    let mut lines = vec![];
+    let mut offset = 0;
+    for paragraph in encoded.cells.split(|c| *c == 255) {
+        let end = offset + paragraph.len();
+        let joins = encoded
+            .joins
+            .iter()
+            .filter(|position| offset <= **position && **position < end)
+            .map(|position| position - offset)
+            .collect::<Vec<_>>();
+        // 한 줄에 다 들어가지 않으면 이어 적는 빈칸에서 나눈다.
+        let mut breaks = encoded
+            .breaks
+            .iter()
+            .filter(|point| offset < point.position && point.position < end)
+            .map(|point| BreakPoint {
+                position: point.position - offset,
+                ..*point
+            })
+            .chain(joins.iter().map(|position| BreakPoint {
+                position: position + 1,
+                trim: 1,
+                mark: None,
+            }))
+            .collect::<Vec<_>>();
+        breaks.sort_by_key(|point| point.position);
+        break_paragraph(paragraph, &breaks, &joins, width, indent, &mut lines);
+        offset = end + 1;
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(lines: Vec<Vec<u8>>) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|c| encode_unicode(*c)).collect())
+            .collect()
+    }
+
+    #[test]
+    pub fn test_can_split_number() {
+        let chars = "1234567".chars().collect::<Vec<_>>();
+        assert!(can_split_number(&chars, 4));
+        assert!(!can_split_number(&chars, 5));
+        let chars = "123456".chars().collect::<Vec<_>>();
+        assert!(!can_split_number(&chars, 3));
+        let chars = "1,234,567원".chars().collect::<Vec<_>>();
+        assert!(can_split_number(&chars, 3));
+    }
+
+    #[test]
+    pub fn test_break_before() {
+        let chars = "사과/배·귤".chars().collect::<Vec<_>>();
+        assert_eq!(break_before(&chars, 1, false, Some(0)), Some((0, None)));
+        assert_eq!(break_before(&chars, 2, false, None), Some((0, None)));
+        assert_eq!(break_before(&chars, 3, false, None), Some((0, None)));
+        assert_eq!(break_before(&chars, 4, false, None), None);
+        assert_eq!(break_before(&chars, 5, false, None), Some((0, None)));
+        let chars = "a//b".chars().collect::<Vec<_>>();
+        assert_eq!(break_before(&chars, 2, false, None), None);
+    }
+
+    #[test]
+    pub fn test_break_lines() {
+        let encoded = Encoded::from(vec![1, 1, 0, 2, 2, 0, 3, 3, 3]);
+        assert_eq!(
+            break_lines(&encoded, 5),
+            vec![vec![1, 1, 0, 2, 2], vec![3, 3, 3]]
+        );
+        assert_eq!(
+            break_lines(&Encoded::from(vec![0, 0, 1, 0, 2, 255, 255, 3]), 4),
+            vec![vec![0, 0, 1], vec![2], vec![], vec![3]]
+        );
+        // 나눌 자리가 없으면 줄 너비에서 자른다.
+        assert_eq!(
+            to_unicode(break_lines(&Encoded::from(vec![1; 5]), 2)),
+            vec!["⠁⠁", "⠁⠁", "⠁"]
+        );
+        let encoded = Encoded {
+            cells: vec![1, 0, 2, 2, 36, 3, 3],
+            breaks: vec![BreakPoint {
+                position: 5,
+                trim: 1,
+                mark: None,
+            }],
+            joins: vec![],
+        };
+        assert_eq!(break_lines(&encoded, 4), vec![vec![1, 0, 2, 2], vec![3, 3]]);
+        // 이어 적는 빈칸에서는 줄을 바꾸지 않고, 한 줄보다 길 때만 나눈다.
+        let encoded = Encoded {
+            cells: vec![1, 1, 0, 2, 0, 3],
+            breaks: vec![],
+            joins: vec![4],
+        };
+        assert_eq!(break_lines(&encoded, 4), vec![vec![1, 1], vec![2, 0, 3]]);
+        assert_eq!(break_lines(&encoded, 2), vec![vec![1, 1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    pub fn test_break_encoded_lines() {
+        let lines = |text: &str, width: usize| {
+            to_unicode(break_lines(
+                &crate::encode_with_breaks(text, Default::default()).unwrap(),
+                width,
+            ))
+        };
+        assert_eq!(
+            lines("대한민국의 수도는 서울입니다", 12),
+            vec!["⠊⠗⠚⠒⠑⠟⠈⠍⠁⠺", "⠠⠍⠊⠥⠉⠵", "⠠⠎⠯⠕⠃⠉⠕⠊"]
+        );
+        assert_eq!(lines("대한민국의", 6), vec!["⠊⠗⠚⠒⠑⠟", "⠈⠍⠁⠺"]);
+        assert_eq!(lines("아예", 2), vec!["⠣", "⠌"]);
+        assert_eq!(lines("나아가", 2), vec!["⠉", "⠣⠫"]);
+        assert_eq!(lines("12345678원", 5), vec!["⠼⠁⠃⠉⠠", "⠙⠑⠠", "⠋⠛⠓⠏⠒"]);
+        assert_eq!(lines("사과/배/감", 6), vec!["⠇⠈⠧⠸⠌", "⠘⠗⠸⠌⠫⠢"]);
+        // 띄어 쓴 날짜는 한 줄에 적는다.
+        assert_eq!(
+            lines("오늘은 2024. 10. 17.", 16),
+            vec!["⠥⠉⠮⠵", "⠼⠃⠚⠃⠙⠲⠀⠼⠁⠚⠲⠀⠼⠁⠛⠲"]
+        );
+        // 로마자 구간은 나누지 않고 다음 줄로 넘긴다.
+        assert_eq!(
+            lines("그는 Washington에 갔다", 13),
+            vec!["⠈⠪⠉⠵", "⠴⠠⠺⠁⠩⠔⠛⠞⠕⠝⠲⠝", "⠫⠌⠊"]
+        );
+    }
+
+    #[test]
+    pub fn test_break_lines_indented() {
+        let encoded = Encoded::from(vec![1, 1, 0, 2, 2, 0, 3, 3, 3, 255, 255, 4]);
+        assert_eq!(
+            break_lines_indented(
+                &encoded,
+                6,
+                Indent {
+                    first: 2,
+                    runover: 0
+                }
+            ),
+            vec![
+                vec![0, 0, 1, 1],
+                vec![2, 2, 0, 3, 3, 3],
+                vec![],
+                vec![0, 0, 4]
+            ]
+        );
+        assert_eq!(
+            break_lines_indented(
+                &encoded,
+                6,
+                Indent {
+                    first: 0,
+                    runover: 2
+                }
+            ),
+            vec![vec![1, 1, 0, 2, 2], vec![0, 0, 3, 3, 3], vec![], vec![4]]
+        );
+    }
+}
@@ -101,6 +101,13 @@ mod tests {
             .stdout(predicate::str::is_empty().not());
     }
 
+    #[test]
+    fn test_braillify_integration_transcriber_note() {
+        let mut cmd = get_built_binary().command();
+        cmd.arg("그림[[TN: 지도]].");
+        cmd.assert().success().stdout("⠈⠪⠐⠕⠢⠀⠸⠦⠨⠕⠊⠥⠴⠇⠲");
+    }
+
     #[test]
     fn test_braillify_pipe_input() {
         let mut cmd = get_built_binary().command();
@@ -0,0 +1,332 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::{
+    book::{Block, BookConfig, Volume, format_book},
+    error::BraillifyError,
+    rich::{Run, push_run},
+    table::{Table, TableCell},
+};
+
+/// 표를 읽는 동안의 상태
+#[derive(Default)]
+struct TableState {
+    table: Table,
+    row: Vec<TableCell>,
+    cell: String,
+}
+
+/// 마크다운 이벤트를 `Block`으로 모은다.
+#[derive(Default)]
+struct Builder {
+    blocks: Vec<Block>,
+    runs: Vec<Run>,
+    /// 지금 글에 적용되는 서식
+    style: Run,
+    emphasis: usize,
+    strong: usize,
+    strike: usize,
+    heading: Option<u8>,
+    quote: usize,
+    /// 목록마다 다음 항목의 번호. 번호 없는 목록은 `None`이다.
+    lists: Vec<Option<u64>>,
+    /// 항목 첫 글 앞에 적을 번호
+    item_number: Option<String>,
+    /// 링크 주소와 링크 글이 시작된 런의 위치
+    link: Option<(String, usize)>,
+    code: Option<String>,
+    table: Option<TableState>,
+}
+
+impl Builder {
+    fn push_text(&mut self, text: &str, code: bool) {
+        if let Some(state) = &mut self.table {
+            state.cell.push_str(text);
+            return;
+        }
+        if let Some(block) = &mut self.code {
+            block.push_str(text);
+            return;
+        }
+        if let Some(number) = self.item_number.take() {
+            self.runs.push(Run::new(number));
+        }
+        push_run(
+            &mut self.runs,
+            Run {
+                text: text.to_string(),
+                code,
+                ..self.style.clone()
+            },
+        );
+    }
+
+    fn update_style(&mut self) {
+        self.style.emphasis = self.emphasis > 0;
+        self.style.bold = self.strong > 0;
+        self.style.strike = self.strike > 0;
+    }
+
+    /// 모은 글을 문단, 항목, 인용문 가운데 알맞은 덩어리로 내보낸다.
+    fn flush(&mut self) {
+        if self.runs.iter().all(|run| run.text.trim().is_empty()) {
+            self.runs.clear();
+            return;
+        }
+        let runs = std::mem::take(&mut self.runs);
+        let block = if let Some(level) = self.heading {
+            Block::Heading { level, runs }
+        } else if !self.lists.is_empty() {
+            Block::ListItem {
+                depth: self.lists.len() - 1,
+                runs,
+            }
+        } else if self.quote > 0 {
+            Block::Quote(runs)
+        } else {
+            Block::Paragraph(runs)
+        };
+        self.blocks.push(block);
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.heading = Some(level as u8);
+            }
+            Tag::Paragraph => self.flush(),
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.quote += 1;
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                self.item_number = self
+                    .lists
+                    .last_mut()
+                    .and_then(Option::as_mut)
+                    .map(|number| {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    });
+            }
+            Tag::CodeBlock(_) => {
+                self.flush();
+                self.code = Some(String::new());
+            }
+            Tag::Emphasis => {
+                self.emphasis += 1;
+                self.update_style();
+            }
+            Tag::Strong => {
+                self.strong += 1;
+                self.update_style();
+            }
+            Tag::Strikethrough => {
+                self.strike += 1;
+                self.update_style();
+            }
+            Tag::Link { dest_url, .. } => {
+                self.link = Some((dest_url.to_string(), self.runs.len()));
+            }
+            Tag::Table(_) => {
+                self.flush();
+                self.table = Some(TableState::default());
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.heading = None;
+            }
+            TagEnd::Paragraph | TagEnd::Item => self.flush(),
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote -= 1;
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+            }
+            TagEnd::CodeBlock => {
+                if let Some(code) = self.code.take() {
+                    self.blocks
+                        .push(Block::Code(code.trim_end_matches('\n').to_string()));
+                }
+            }
+            TagEnd::Emphasis => {
+                self.emphasis -= 1;
+                self.update_style();
+            }
+            TagEnd::Strong => {
+                self.strong -= 1;
+                self.update_style();
+            }
+            TagEnd::Strikethrough => {
+                self.strike -= 1;
+                self.update_style();
+            }
+            TagEnd::Link => {
+                if let Some((url, start)) = self.link.take() {
+                    let text = self.runs[start..]
+                        .iter()
+                        .map(|run| run.text.as_str())
+                        .collect::<String>();
+                    // 링크 글 뒤에 주소를 컴퓨터 점자로 적는다. 주소만 있는 링크는 그대로 둔다.
+                    if text.trim() != url && !url.is_empty() {
+                        self.push_text(" ", false);
+                        self.push_text(&url, true);
+                    }
+                }
+            }
+            TagEnd::TableCell => {
+                if let Some(state) = &mut self.table {
+                    let text = std::mem::take(&mut state.cell);
+                    state.row.push(TableCell::new(text.trim()));
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(state) = &mut self.table {
+                    let row = std::mem::take(&mut state.row);
+                    state.table.rows.push(row);
+                    if tag == TagEnd::TableHead {
+                        state.table.header_rows = 1;
+                    }
+                }
+            }
+            TagEnd::Table => {
+                if let Some(state) = self.table.take() {
+                    self.blocks.push(Block::Table(state.table));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// CommonMark 문서를 점자 책의 덩어리로 나눈다.
+/// 기울임(`*`, `_`)은 드러냄표로, 굵게는 굵은 글자로, 코드는 컴퓨터 점자로 적고,
+/// 링크는 글 뒤에 주소를 덧붙인다. 번호 없는 목록의 항목 기호는 적지 않고 들여쓰기로 구별한다.
+pub fn parse_markdown(source: &str) -> Vec<Block> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut builder = Builder::default();
+    for event in Parser::new_ext(source, options) {
+        match event {
+            Event::Start(tag) => builder.start(tag),
+            Event::End(tag) => builder.end(tag),
+            Event::Text(text) => builder.push_text(&text, false),
+            Event::Code(code) => builder.push_text(&code, true),
+            Event::SoftBreak => builder.push_text(" ", false),
+            Event::HardBreak => builder.push_text("\n", false),
+            _ => {}
+        }
+    }
+    builder.flush();
+    builder.blocks
+}
+
+/// 마크다운 문서를 점자 책으로 배치한다.
+pub fn encode_markdown(source: &str, config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_markdown(source), config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_markdown() {
+        let blocks = parse_markdown(
+            "# 제목\n\n서울은 *대한민국*의 **수도**이다.\n\n1. 사과\n2. 배\n   - 감\n\n> 인용\n",
+        );
+        assert_eq!(
+            blocks,
+            vec![
+                Block::heading(1, "제목"),
+                Block::Paragraph(vec![
+                    Run::new("서울은 "),
+                    Run {
+                        emphasis: true,
+                        ..Run::new("대한민국")
+                    },
+                    Run::new("의 "),
+                    Run {
+                        bold: true,
+                        ..Run::new("수도")
+                    },
+                    Run::new("이다."),
+                ]),
+                Block::list_item(0, "1. 사과"),
+                Block::list_item(0, "2. 배"),
+                Block::list_item(1, "감"),
+                Block::Quote(vec![Run::new("인용")]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_markdown_code_link_table() {
+        let blocks = parse_markdown(
+            "`ls` 명령과 [누리집](https://braillify.kr)\n\n```\nfn main() {}\n```\n\n| 과일 | 값 |\n|---|---|\n| 사과 | 1000 |\n",
+        );
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Paragraph(vec![
+                    Run {
+                        code: true,
+                        ..Run::new("ls")
+                    },
+                    Run::new(" 명령과 누리집 "),
+                    Run {
+                        code: true,
+                        ..Run::new("https://braillify.kr")
+                    },
+                ]),
+                Block::Code("fn main() {}".to_string()),
+                Block::Table(Table::from_rows(
+                    [vec!["과일", "값"], vec!["사과", "1000"]],
+                    1
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_encode_markdown() {
+        let config = BookConfig {
+            page_numbers: false,
+            table_of_contents: false,
+            ..Default::default()
+        };
+        let volumes = encode_markdown("서울은 *수도*이다.", &config).unwrap();
+        assert_eq!(
+            volumes[0].pages[0].lines,
+            vec![
+                crate::encode_rich(&[
+                    Run::new("서울은 "),
+                    Run {
+                        emphasis: true,
+                        ..Run::new("수도")
+                    },
+                    Run::new("이다."),
+                ])
+                .map(|cells| [vec![0, 0], cells].concat())
+                .unwrap()
+            ]
+        );
+        // `*`와 `_`는 기호로 남지 않는다.
+        assert_eq!(
+            encode_markdown("*a* _b_", &config).unwrap(),
+            encode_markdown("*a* *b*", &config).unwrap()
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    english, error::BraillifyError, fraction, math_symbol_shortcut, number, symbol_shortcut,
+    unicode::decode_unicode,
+};
+
+/// 수표
+const NUMBER_SIGN: u8 = decode_unicode('⠼');
+/// 분수표
+const FRACTION_SIGN: u8 = decode_unicode('⠌');
+/// 윗첨자(지수)표
+const SUPERSCRIPT_SIGN: u8 = decode_unicode('⠘');
+/// 아래첨자표
+const SUBSCRIPT_SIGN: u8 = decode_unicode('⠰');
+/// 근호 시작표
+const ROOT_START: u8 = decode_unicode('⠜');
+/// 근호 종료표
+const ROOT_END: u8 = decode_unicode('⠻');
+/// 기호를 얹은 식의 시작표
+const MODIFIED_START: u8 = decode_unicode('⠐');
+/// 식 바로 위에 얹은 기호 앞에 적는 표
+const DIRECTLY_OVER: u8 = decode_unicode('⠣');
+/// 식 바로 아래에 얹은 기호 앞에 적는 표
+const DIRECTLY_UNDER: u8 = decode_unicode('⠩');
+/// 기호를 얹은 식의 종료표
+const MODIFIED_END: u8 = decode_unicode('⠻');
+/// 숫자 뒤에 a~j가 이어질 때 숫자와 구별하기 위해 적는 연속표
+const CONTINUATION_SIGN: u8 = decode_unicode('⠰');
+
+/// 변수와 구별하여 한 단어로 읽는 함수 이름
+pub const FUNCTIONS: [&str; 15] = [
+    "sin", "cos", "tan", "sec", "csc", "cot", "log", "ln", "exp", "lim", "max", "min", "sinh",
+    "cosh", "tanh",
+];
+
+/// LaTeX, MathML 등에서 읽어 들인 수식의 구조
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathNode {
+    /// 숫자(소수점 포함)
+    Number(String),
+    /// 변수(로마자, 그리스 문자)
+    Identifier(char),
+    /// sin, log 같은 함수 이름
+    Function(String),
+    /// 연산 기호, 비교 기호, 문장 부호
+    Operator(char),
+    /// 분자, 분모
+    Fraction(Vec<MathNode>, Vec<MathNode>),
+    /// 근수(거듭제곱근의 지수), 근호 안의 식
+    Root(Option<Vec<MathNode>>, Vec<MathNode>),
+    /// 바로 앞 항의 윗첨자
+    Superscript(Vec<MathNode>),
+    /// 바로 앞 항의 아래첨자
+    Subscript(Vec<MathNode>),
+    /// 식 위에 얹은 기호(x̄의 윗줄, 벡터의 화살표)와 그 식
+    Over(char, Vec<MathNode>),
+    /// 식 아래에 얹은 기호(밑줄 등)와 그 식
+    Under(char, Vec<MathNode>),
+    /// 여는 괄호, 괄호 안의 식, 닫는 괄호
+    Fenced(char, Vec<MathNode>, char),
+    /// 행렬, 연립방정식 등의 표(행 > 칸 > 식)
+    Table(Vec<Vec<Vec<MathNode>>>),
+    /// 수식 안의 일반 글자
+    Text(String),
+}
+
+fn encode_symbol(c: char) -> Result<Vec<u8>, BraillifyError> {
+    if math_symbol_shortcut::is_math_symbol_char(c) {
+        Ok(math_symbol_shortcut::encode_char_math_symbol_shortcut(c)?.to_vec())
+    } else {
+        Ok(symbol_shortcut::encode_char_symbol_shortcut(c)?.to_vec())
+    }
+}
+
+/// 식 위아래에 얹는 기호. 표에 없는 기호는 일반 기호와 같이 적는다.
+fn encode_mark(c: char) -> Result<Vec<u8>, BraillifyError> {
+    match c {
+        '¯' | '‾' | '_' | '―' | '\u{0305}' => Ok(vec![decode_unicode('⠱')]),
+        '^' | 'ˆ' => Ok(vec![decode_unicode('⠸'), decode_unicode('⠣')]),
+        '˙' => Ok(vec![decode_unicode('⠡')]),
+        '˜' => encode_symbol('~'),
+        '\u{20D7}' => encode_symbol('→'),
+        c => encode_symbol(c),
+    }
+}
+
+/// 기호를 얹은 식은 시작표, 식, 위아래 표, 기호, 종료표 순으로 적어 첨자와 구별한다.
+fn encode_modified(base: &[MathNode], position: u8, mark: char) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = vec![MODIFIED_START];
+    result.extend(encode_math(base)?);
+    result.push(position);
+    result.extend(encode_mark(mark)?);
+    result.push(MODIFIED_END);
+    Ok(result)
+}
+
+fn single_number(nodes: &[MathNode]) -> Option<&str> {
+    match nodes {
+        [MathNode::Number(n)] if n.chars().all(|c| c.is_ascii_digit()) => Some(n),
+        _ => None,
+    }
+}
+
+fn encode_parenthesized(nodes: &[MathNode]) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = encode_symbol('(')?;
+    result.extend(encode_math(nodes)?);
+    result.extend(encode_symbol(')')?);
+    Ok(result)
+}
+
+/// 첨자, 분자, 분모 등이 두 항 이상이면 괄호로 묶어 범위를 나타낸다.
+fn encode_group(nodes: &[MathNode]) -> Result<Vec<u8>, BraillifyError> {
+    if nodes.len() <= 1 {
+        return encode_math(nodes);
+    }
+    encode_parenthesized(nodes)
+}
+
+/// 분자나 분모가 분수이면 괄호로 묶어 어느 분수표가 전체 분수의 것인지 나타낸다.
+fn encode_fraction_part(nodes: &[MathNode]) -> Result<Vec<u8>, BraillifyError> {
+    if let [MathNode::Fraction(..)] = nodes {
+        return encode_parenthesized(nodes);
+    }
+    encode_group(nodes)
+}
+
+/// 수식을 수학 점자로 바꾼다.
+pub fn encode_math(nodes: &[MathNode]) -> Result<Vec<u8>, BraillifyError> {
+    let mut result = vec![];
+    let mut after_number = false;
+    for (i, node) in nodes.iter().enumerate() {
+        match node {
+            MathNode::Number(n) => {
+                // 제40항 숫자는 수표 ⠼을 앞세워 적는다.
+                result.push(NUMBER_SIGN);
+                for c in n.chars() {
+                    match c {
+                        // 제48항 소수점은 ⠲으로 적는다.
+                        '.' => result.push(decode_unicode('⠲')),
+                        // 제41항 숫자 사이의 쉼표와 자릿점은 ⠂으로 적는다.
+                        ',' => result.push(decode_unicode('⠂')),
+                        _ => result.push(number::encode_number(c)?),
+                    }
+                }
+            }
+            MathNode::Identifier(c) => {
+                if math_symbol_shortcut::is_greek_char(*c) {
+                    result.extend(math_symbol_shortcut::encode_greek_char(*c)?);
+                } else {
+                    if after_number && ('a'..='j').contains(&c.to_ascii_lowercase()) {
+                        result.push(CONTINUATION_SIGN);
+                    }
+                    if c.is_uppercase() {
+                        result.push(decode_unicode('⠠'));
+                    }
+                    result.extend(english::encode_english_letter(*c)?);
+                }
+            }
+            MathNode::Function(name) => {
+                for c in name.chars() {
+                    result.extend(english::encode_english_letter(c)?);
+                }
+                if matches!(
+                    nodes.get(i + 1),
+                    Some(MathNode::Identifier(_) | MathNode::Number(_))
+                ) {
+                    // 함수 이름과 변수는 한 칸 띄어 구별한다.
+                    result.push(0);
+                }
+            }
+            MathNode::Operator(c) => result.extend(encode_symbol(*c)?),
+            MathNode::Fraction(numerator, denominator) => {
+                // 제47항 분수는 분모, 분수표, 분자 순으로 적는다.
+                if let (Some(num), Some(den)) =
+                    (single_number(numerator), single_number(denominator))
+                {
+                    result.extend(fraction::encode_fraction(num, den)?);
+                } else {
+                    result.extend(encode_fraction_part(denominator)?);
+                    result.push(FRACTION_SIGN);
+                    result.extend(encode_fraction_part(numerator)?);
+                }
+            }
+            MathNode::Root(index, radicand) => {
+                if let Some(index) = index {
+                    result.push(SUPERSCRIPT_SIGN);
+                    result.extend(encode_group(index)?);
+                }
+                result.push(ROOT_START);
+                result.extend(encode_math(radicand)?);
+                result.push(ROOT_END);
+            }
+            MathNode::Superscript(script) => {
+                result.push(SUPERSCRIPT_SIGN);
+                result.extend(encode_group(script)?);
+            }
+            MathNode::Subscript(script) => {
+                result.push(SUBSCRIPT_SIGN);
+                result.extend(encode_group(script)?);
+            }
+            MathNode::Over(mark, base) => {
+                result.extend(encode_modified(base, DIRECTLY_OVER, *mark)?);
+            }
+            MathNode::Under(mark, base) => {
+                result.extend(encode_modified(base, DIRECTLY_UNDER, *mark)?);
+            }
+            MathNode::Fenced(open, body, close) => {
+                result.extend(encode_symbol(*open)?);
+                result.extend(encode_math(body)?);
+                result.extend(encode_symbol(*close)?);
+            }
+            MathNode::Table(rows) => {
+                // 표의 각 칸은 한 칸 띄어 쓰고, 각 행은 줄을 바꾸어 적는다.
+                for (row_index, row) in rows.iter().enumerate() {
+                    if row_index > 0 {
+                        result.push(255);
+                    }
+                    for (cell_index, cell) in row.iter().enumerate() {
+                        if cell_index > 0 {
+                            result.push(0);
+                        }
+                        result.extend(encode_math(cell)?);
+                    }
+                }
+            }
+            MathNode::Text(text) => result.extend(crate::encode(text)?),
+        }
+        after_number = matches!(node, MathNode::Number(_));
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_encode_math_number_and_identifier() {
+        // 2a -> 숫자 뒤 a~j 앞에는 연속표
+        assert_eq!(
+            encode_math(&[MathNode::Number("2".into()), MathNode::Identifier('a')]).unwrap(),
+            vec![NUMBER_SIGN, 3, CONTINUATION_SIGN, 1]
+        );
+        // 2x
+        assert_eq!(
+            encode_math(&[MathNode::Number("2".into()), MathNode::Identifier('x')]).unwrap(),
+            vec![NUMBER_SIGN, 3, decode_unicode('⠭')]
+        );
+        assert_eq!(
+            encode_math(&[MathNode::Number("3.14".into())]).unwrap(),
+            vec![NUMBER_SIGN, 9, 50, 1, 25]
+        );
+    }
+
+    #[test]
+    pub fn test_encode_math_fraction() {
+        assert_eq!(
+            encode_math(&[MathNode::Fraction(
+                vec![MathNode::Number("3".into())],
+                vec![MathNode::Number("4".into())]
+            )])
+            .unwrap(),
+            fraction::encode_fraction("3", "4").unwrap()
+        );
+        assert_eq!(
+            encode_math(&[MathNode::Fraction(
+                vec![MathNode::Identifier('a')],
+                vec![MathNode::Identifier('b')]
+            )])
+            .unwrap(),
+            vec![decode_unicode('⠃'), FRACTION_SIGN, decode_unicode('⠁')]
+        );
+        let half = MathNode::Fraction(
+            vec![MathNode::Identifier('a')],
+            vec![MathNode::Identifier('b')],
+        );
+        let c = vec![MathNode::Identifier('c')];
+        assert_ne!(
+            encode_math(&[MathNode::Fraction(vec![half.clone()], c.clone())]).unwrap(),
+            encode_math(&[MathNode::Fraction(c, vec![half])]).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_encode_math_scripts_and_root() {
+        assert_eq!(
+            encode_math(&[
+                MathNode::Identifier('x'),
+                MathNode::Superscript(vec![MathNode::Number("2".into())])
+            ])
+            .unwrap(),
+            vec![decode_unicode('⠭'), SUPERSCRIPT_SIGN, NUMBER_SIGN, 3]
+        );
+        assert_eq!(
+            encode_math(&[MathNode::Root(None, vec![MathNode::Identifier('x')])]).unwrap(),
+            vec![ROOT_START, decode_unicode('⠭'), ROOT_END]
+        );
+    }
+
+    #[test]
+    pub fn test_encode_math_function_spacing() {
+        assert_eq!(
+            encode_math(&[MathNode::Function("sin".into()), MathNode::Identifier('x')]).unwrap(),
+            vec![
+                decode_unicode('⠎'),
+                decode_unicode('⠊'),
+                decode_unicode('⠝'),
+                0,
+                decode_unicode('⠭')
+            ]
+        );
+    }
+}
@@ -10,6 +10,80 @@ static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     '=' => &[decode_unicode('⠒'),decode_unicode('⠒')],
     '>' => &[decode_unicode('⠢'),decode_unicode('⠢')],
     '<' => &[decode_unicode('⠔'),decode_unicode('⠔')],
+    '≠' => &[decode_unicode('⠸'),decode_unicode('⠒'),decode_unicode('⠒')],
+    '≤' => &[decode_unicode('⠔'),decode_unicode('⠔'),decode_unicode('⠒')],
+    '≥' => &[decode_unicode('⠢'),decode_unicode('⠢'),decode_unicode('⠒')],
+    '±' => &[decode_unicode('⠢'),decode_unicode('⠔')],
+    '∓' => &[decode_unicode('⠔'),decode_unicode('⠢')],
+    '√' => &[decode_unicode('⠜')],
+    '∞' => &[decode_unicode('⠿')],
+    'π' => &[decode_unicode('⠨'),decode_unicode('⠏')],
+    '∑' => &[decode_unicode('⠠'),decode_unicode('⠨'),decode_unicode('⠎')],
+    '∫' => &[decode_unicode('⠮')],
+    '∠' => &[decode_unicode('⠸'),decode_unicode('⠪')],
+    '⊥' => &[decode_unicode('⠸'),decode_unicode('⠏')],
+    '∥' => &[decode_unicode('⠸'),decode_unicode('⠇')],
+    '∈' => &[decode_unicode('⠈'),decode_unicode('⠑')],
+    '⊂' => &[decode_unicode('⠸'),decode_unicode('⠦')],
+    '∪' => &[decode_unicode('⠸'),decode_unicode('⠬')],
+    '∩' => &[decode_unicode('⠸'),decode_unicode('⠩')],
+    '∴' => &[decode_unicode('⠠'),decode_unicode('⠡')],
+    '∵' => &[decode_unicode('⠈'),decode_unicode('⠌')],
+    '°' => &[decode_unicode('⠘'),decode_unicode('⠚')],
+    '′' => &[decode_unicode('⠶')],
+    '″' => &[decode_unicode('⠶'),decode_unicode('⠶')],
+    '→' => &[decode_unicode('⠒'),decode_unicode('⠒'),decode_unicode('⠕')],
+    '←' => &[decode_unicode('⠪'),decode_unicode('⠒'),decode_unicode('⠒')],
+    '↔' => &[decode_unicode('⠪'),decode_unicode('⠒'),decode_unicode('⠒'),decode_unicode('⠕')],
+    '⇌' => &[decode_unicode('⠪'),decode_unicode('⠒'),decode_unicode('⠒'),decode_unicode('⠕')],
+};
+
+/// 제46항 앞뒤를 띄어 쓰는 연산 기호와 관계 기호
+const SPACED_OPERATORS: [char; 17] = [
+    '+', '−', '×', '÷', '=', '>', '<', '≠', '≤', '≥', '±', '∓', '∈', '⊂', '∪', '∩', '⊥',
+];
+
+/// 윗첨자 숫자는 ⠘, 아래첨자 숫자는 ⠰ 뒤에 수표와 숫자를 적는다.
+fn script_digit(text: char) -> Option<(u8, char)> {
+    let (prefix, digit) = match text {
+        '⁰' => (decode_unicode('⠘'), 0),
+        '¹' => (decode_unicode('⠘'), 1),
+        '²' => (decode_unicode('⠘'), 2),
+        '³' => (decode_unicode('⠘'), 3),
+        '⁴'..='⁹' => (decode_unicode('⠘'), text as u32 - '⁰' as u32),
+        '₀'..='₉' => (decode_unicode('⠰'), text as u32 - '₀' as u32),
+        _ => return None,
+    };
+    Some((prefix, char::from_digit(digit, 10)?))
+}
+
+/// 그리스 문자는 그리스 문자표 ⠨ 뒤에 해당 글자의 점형을 적는다.
+static GREEK_MAP: phf::Map<char, u8> = phf_map! {
+    'α' => decode_unicode('⠁'),
+    'β' => decode_unicode('⠃'),
+    'γ' => decode_unicode('⠛'),
+    'δ' => decode_unicode('⠙'),
+    'ε' => decode_unicode('⠑'),
+    'ζ' => decode_unicode('⠵'),
+    'η' => decode_unicode('⠱'),
+    'θ' => decode_unicode('⠹'),
+    'ι' => decode_unicode('⠊'),
+    'κ' => decode_unicode('⠅'),
+    'λ' => decode_unicode('⠇'),
+    'μ' => decode_unicode('⠍'),
+    'ν' => decode_unicode('⠝'),
+    'ξ' => decode_unicode('⠭'),
+    'ο' => decode_unicode('⠕'),
+    'π' => decode_unicode('⠏'),
+    'ρ' => decode_unicode('⠗'),
+    'σ' => decode_unicode('⠎'),
+    'ς' => decode_unicode('⠎'),
+    'τ' => decode_unicode('⠞'),
+    'υ' => decode_unicode('⠥'),
+    'φ' => decode_unicode('⠋'),
+    'χ' => decode_unicode('⠯'),
+    'ψ' => decode_unicode('⠽'),
+    'ω' => decode_unicode('⠺'),
 };
 
 pub fn encode_char_math_symbol_shortcut(text: char) -> Result<&'static [u8], BraillifyError> {
@@ -21,7 +95,96 @@ pub fn encode_char_math_symbol_shortcut(text: char) -> Result<&'static [u8], Bra
 }
 
 pub fn is_math_symbol_char(text: char) -> bool {
-    SHORTCUT_MAP.contains_key(&text)
+    SHORTCUT_MAP.contains_key(&text) || script_digit(text).is_some()
+}
+
+pub fn is_spaced_operator(text: char) -> bool {
+    SPACED_OPERATORS.contains(&text)
+}
+
+/// 이어 나오는 윗첨자(아래첨자) 숫자를 한 번에 적는다. (x²³ → ⠭⠘⠼⠃⠉)
+/// 적은 점자와 읽은 글자 수를 돌려준다.
+pub fn encode_script_digits(chars: &[char]) -> Option<(Vec<u8>, usize)> {
+    let (prefix, _) = script_digit(*chars.first()?)?;
+    let mut result = vec![prefix, decode_unicode('⠼')];
+    let mut len = 0;
+    for c in chars {
+        match script_digit(*c) {
+            Some((p, digit)) if p == prefix => {
+                result.push(crate::number::encode_number(digit).ok()?);
+                len += 1;
+            }
+            _ => break,
+        }
+    }
+    Some((result, len))
+}
+
+/// 식의 변수로 쓰인 로마자 한 글자인지 여부. 앞뒤에 다른 로마자가 붙지 않아야 한다. (2x의 x)
+fn is_variable(chars: &[char], i: usize) -> bool {
+    chars.get(i).is_some_and(|c| c.is_ascii_alphabetic())
+        && !(i > 0 && chars[i - 1].is_alphabetic())
+        && !chars.get(i + 1).is_some_and(|c| c.is_alphabetic())
+}
+
+/// `-`가 붙임표가 아니라 빼기표(음수 부호)로 쓰였는지 판단한다.
+/// - 단어 처음이나 연산 기호, 여는 괄호 바로 뒤에서 숫자 앞에 올 때 (-3, =-3, (-3)
+/// - 같은 단어 안에 다른 연산 기호가 있을 때 (5-3=2, a-b=c)
+/// - 변수 소문자 뒤에서 숫자 앞에 올 때 (x-1)
+/// - 홀로 띄어 쓴 `-`의 앞뒤가 숫자나 변수이고 식 안에 다른 연산 기호가 있을 때 (5 - 3 = 2)
+pub fn is_minus_sign(word_chars: &[char], i: usize, prev_word: &str, remaining_words: &[&str]) -> bool {
+    let is_number_start = |c: &char| c.is_ascii_digit() || *c == '(';
+    if word_chars.len() == 1 {
+        let prev = prev_word.chars().collect::<Vec<_>>();
+        let next = remaining_words
+            .first()
+            .map(|w| w.chars().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let left = prev.last().is_some_and(|c| c.is_ascii_digit())
+            || (!prev.is_empty() && is_variable(&prev, prev.len() - 1));
+        let right = next.first().is_some_and(is_number_start) || is_variable(&next, 0);
+        return left
+            && right
+            && (prev_word.chars().any(is_spaced_operator)
+                || remaining_words.iter().any(|w| w.chars().any(is_spaced_operator)));
+    }
+    let is_math = word_chars.iter().any(|c| is_spaced_operator(*c));
+    let next_number = word_chars.get(i + 1).is_some_and(is_number_start);
+    if !next_number && !is_variable(word_chars, i + 1) {
+        return false;
+    }
+    if i == 0 {
+        return next_number || is_math;
+    }
+    let prev = word_chars[i - 1];
+    if prev == '(' || is_math_symbol_char(prev) {
+        return true;
+    }
+    if is_variable(word_chars, i - 1) {
+        return is_math || (prev.is_ascii_lowercase() && next_number);
+    }
+    (prev.is_ascii_digit() || prev == ')') && is_math
+}
+
+pub fn is_greek_char(text: char) -> bool {
+    text.to_lowercase()
+        .next()
+        .is_some_and(|c| GREEK_MAP.contains_key(&c))
+}
+
+/// 그리스 대문자는 그리스 문자표 앞에 대문자 기호표 ⠠을 적는다.
+pub fn encode_greek_char(text: char) -> Result<Vec<u8>, BraillifyError> {
+    let lower = text.to_lowercase().next().unwrap_or(text);
+    let Some(code) = GREEK_MAP.get(&lower) else {
+        return Err(BraillifyError::InvalidMathSymbolCharacter { character: text, position: None });
+    };
+    let mut result = vec![];
+    if text.is_uppercase() {
+        result.push(decode_unicode('⠠'));
+    }
+    result.push(decode_unicode('⠨'));
+    result.push(*code);
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -37,7 +200,61 @@ mod test {
         assert!(is_math_symbol_char('='));
         assert!(is_math_symbol_char('>'));
         assert!(is_math_symbol_char('<'));
+        assert!(is_math_symbol_char('≤'));
+        assert!(is_math_symbol_char('≥'));
+        assert!(is_math_symbol_char('≠'));
+        assert!(is_math_symbol_char('±'));
+        assert!(is_math_symbol_char('√'));
+        assert!(is_math_symbol_char('∴'));
+        assert!(is_math_symbol_char('²'));
+        assert!(is_math_symbol_char('₃'));
         assert!(!is_math_symbol_char('a'));
+        assert!(!is_math_symbol_char('-'));
+    }
+
+    #[test]
+    pub fn test_encode_script_digits() {
+        assert_eq!(
+            encode_script_digits(&['²', '³', 'x']).unwrap(),
+            (vec![24, 60, 3, 9], 2)
+        );
+        assert_eq!(encode_script_digits(&['₁', '²']).unwrap(), (vec![48, 60, 1], 1));
+        assert!(encode_script_digits(&['2']).is_none());
+    }
+
+    #[test]
+    pub fn test_is_minus_sign() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert!(is_minus_sign(&chars("-3"), 0, "", &[]));
+        assert!(is_minus_sign(&chars("5-3=2"), 1, "", &[]));
+        assert!(is_minus_sign(&chars("x=-3"), 2, "", &[]));
+        assert!(is_minus_sign(&chars("-"), 0, "5", &["3", "=", "2"]));
+        assert!(!is_minus_sign(&chars("02-2669-9775~6"), 2, "", &[]));
+        assert!(!is_minus_sign(&chars("-"), 0, "5", &["3"]));
+        assert!(!is_minus_sign(&chars("A-B"), 1, "", &[]));
+        assert!(is_minus_sign(&chars("x-1"), 1, "", &[]));
+        assert!(is_minus_sign(&chars("a-b=c"), 1, "", &[]));
+        assert!(is_minus_sign(&chars("2x-y=1"), 2, "", &[]));
+        assert!(is_minus_sign(&chars("-"), 0, "a", &["b", "=", "c"]));
+        assert!(!is_minus_sign(&chars("e-mail"), 1, "", &[]));
+        assert!(!is_minus_sign(&chars("-v"), 0, "", &[]));
+        assert!(!is_minus_sign(&chars("x-ray"), 1, "", &[]));
+    }
+
+    #[test]
+    pub fn test_encode_greek_char() {
+        assert!(is_greek_char('π'));
+        assert!(is_greek_char('Ω'));
+        assert!(!is_greek_char('p'));
+        assert_eq!(
+            encode_greek_char('π').unwrap(),
+            vec![decode_unicode('⠨'), decode_unicode('⠏')]
+        );
+        assert_eq!(
+            encode_greek_char('Δ').unwrap(),
+            vec![decode_unicode('⠠'), decode_unicode('⠨'), decode_unicode('⠙')]
+        );
+        assert!(encode_greek_char('p').is_err());
     }
 
     #[test]
@@ -0,0 +1,365 @@
+use crate::{
+    error::BraillifyError,
+    math::{FUNCTIONS, MathNode, encode_math},
+    xml::{XmlElement, XmlNode, parse_xml},
+};
+
+const MATH_OPEN: &str = "<math";
+const MATH_CLOSE: &str = "</math>";
+/// 첨자가 아니라 식 위아래에 얹는 기호로 보는 글자
+const ACCENTS: [char; 12] = [
+    '¯', '‾', '_', '―', '~', '˜', '^', 'ˆ', '˙', '→', '\u{20D7}', '\u{0305}',
+];
+
+fn error(element: &XmlElement, error: impl Into<String>) -> BraillifyError {
+    BraillifyError::MathParseError {
+        input: element.name.clone(),
+        error: error.into(),
+    }
+}
+
+fn children(element: &XmlElement) -> Result<Vec<MathNode>, BraillifyError> {
+    let mut nodes = vec![];
+    for child in element.elements() {
+        nodes.extend(convert(child)?);
+    }
+    Ok(nodes)
+}
+
+/// mfrac, msup처럼 인자 개수가 정해진 요소의 자식 요소를 각각 변환한다.
+fn arguments(element: &XmlElement, count: usize) -> Result<Vec<Vec<MathNode>>, BraillifyError> {
+    let args = element
+        .elements()
+        .map(convert)
+        .collect::<Result<Vec<_>, _>>()?;
+    if args.len() != count {
+        return Err(error(
+            element,
+            format!("expected {} children, found {}", count, args.len()),
+        ));
+    }
+    Ok(args)
+}
+
+fn convert_operator(text: &str) -> Vec<MathNode> {
+    text.chars()
+        .filter_map(|c| match c {
+            // 보이지 않는 곱셈, 함수 적용, 쉼표는 점자로 적지 않는다.
+            '\u{2061}'..='\u{2063}' => None,
+            '-' => Some(MathNode::Operator('−')),
+            '*' | '⋅' => Some(MathNode::Operator('×')),
+            c if c.is_whitespace() => None,
+            c => Some(MathNode::Operator(c)),
+        })
+        .collect()
+}
+
+fn convert_identifier(text: &str) -> Vec<MathNode> {
+    if FUNCTIONS.contains(&text) {
+        vec![MathNode::Function(text.to_string())]
+    } else {
+        text.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(MathNode::Identifier)
+            .collect()
+    }
+}
+
+fn convert_fenced(element: &XmlElement) -> Result<Vec<MathNode>, BraillifyError> {
+    let open = element.attribute("open").unwrap_or("(");
+    let close = element.attribute("close").unwrap_or(")");
+    let separators = element
+        .attribute("separators")
+        .unwrap_or(",")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<Vec<_>>();
+    let mut body = vec![];
+    for (i, child) in element.elements().enumerate() {
+        if i > 0
+            && let Some(sep) = separators.get(i - 1).or(separators.last())
+        {
+            body.push(MathNode::Operator(*sep));
+        }
+        body.extend(convert(child)?);
+    }
+    let mut open_chars = open.chars();
+    let mut close_chars = close.chars();
+    match (
+        open_chars.next(),
+        open_chars.next(),
+        close_chars.next(),
+        close_chars.next(),
+    ) {
+        (Some(open), None, Some(close), None) => Ok(vec![MathNode::Fenced(open, body, close)]),
+        _ => {
+            let mut nodes = convert_operator(open);
+            nodes.extend(body);
+            nodes.extend(convert_operator(close));
+            Ok(nodes)
+        }
+    }
+}
+
+/// mover, munder의 첨자가 악센트(x̄의 윗줄, 벡터의 화살표)이면 그 기호를 돌려준다.
+/// `accent` 속성이 없으면 윗줄, 물결, 삿갓, 점, 화살표 하나로 된 mo를 악센트로 본다.
+fn accent_mark(element: &XmlElement, attribute: &str) -> Option<char> {
+    let script = element.elements().nth(1)?;
+    if script.local_name() != "mo" {
+        return None;
+    }
+    let text = script.text();
+    let mut chars = text.trim().chars();
+    let (Some(mark), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    let is_accent = match element.attribute(attribute).or(script.attribute(attribute)) {
+        Some(value) => value == "true",
+        None => ACCENTS.contains(&mark),
+    };
+    is_accent.then_some(mark)
+}
+
+/// 악센트를 얹은 식은 기호를 얹은 식으로, 나머지는 lim, ∑의 극한처럼 첨자로 읽는다.
+fn convert_under_over(element: &XmlElement) -> Result<Vec<MathNode>, BraillifyError> {
+    let is_over = element.local_name() == "mover";
+    let mut args = arguments(element, 2)?;
+    let script = args.pop().unwrap_or_default();
+    let mut nodes = args.pop().unwrap_or_default();
+    let attribute = if is_over { "accent" } else { "accentunder" };
+    Ok(match (accent_mark(element, attribute), is_over) {
+        (Some(mark), true) => vec![MathNode::Over(mark, nodes)],
+        (Some(mark), false) => vec![MathNode::Under(mark, nodes)],
+        (None, true) => {
+            nodes.push(MathNode::Superscript(script));
+            nodes
+        }
+        (None, false) => {
+            nodes.push(MathNode::Subscript(script));
+            nodes
+        }
+    })
+}
+
+fn convert_table(element: &XmlElement) -> Result<Vec<MathNode>, BraillifyError> {
+    let mut rows = vec![];
+    for row in element.elements() {
+        match row.local_name() {
+            "mtr" | "mlabeledtr" => {
+                let cells = row
+                    .elements()
+                    .map(children)
+                    .collect::<Result<Vec<_>, _>>()?;
+                rows.push(cells);
+            }
+            name => return Err(error(element, format!("unexpected <{}> in mtable", name))),
+        }
+    }
+    Ok(vec![MathNode::Table(rows)])
+}
+
+/// MathML 요소를 수식 구조로 바꾼다.
+fn convert(element: &XmlElement) -> Result<Vec<MathNode>, BraillifyError> {
+    let text = || element.text().trim().to_string();
+    Ok(match element.local_name() {
+        "math" | "mrow" | "mstyle" | "mpadded" | "menclose" | "merror" | "mtd" => {
+            children(element)?
+        }
+        // semantics의 첫 자식만 표시용 수식이고 나머지는 주석이다.
+        "semantics" => match element.elements().next() {
+            Some(first) => convert(first)?,
+            None => vec![],
+        },
+        "mspace" | "mphantom" | "annotation" | "annotation-xml" | "none" => vec![],
+        "mn" => vec![MathNode::Number(text())],
+        "mi" => convert_identifier(&text()),
+        "mo" => convert_operator(&text()),
+        "mtext" | "ms" => {
+            let text = text();
+            if text.is_empty() {
+                vec![]
+            } else {
+                vec![MathNode::Text(text)]
+            }
+        }
+        "mfrac" => {
+            let mut args = arguments(element, 2)?;
+            let denominator = args.pop().unwrap_or_default();
+            let numerator = args.pop().unwrap_or_default();
+            vec![MathNode::Fraction(numerator, denominator)]
+        }
+        "msqrt" => vec![MathNode::Root(None, children(element)?)],
+        "mroot" => {
+            let mut args = arguments(element, 2)?;
+            let index = args.pop().unwrap_or_default();
+            let radicand = args.pop().unwrap_or_default();
+            vec![MathNode::Root(Some(index), radicand)]
+        }
+        "mover" | "munder" => convert_under_over(element)?,
+        "msup" => {
+            let mut args = arguments(element, 2)?;
+            let script = args.pop().unwrap_or_default();
+            let mut nodes = args.pop().unwrap_or_default();
+            nodes.push(MathNode::Superscript(script));
+            nodes
+        }
+        "msub" => {
+            let mut args = arguments(element, 2)?;
+            let script = args.pop().unwrap_or_default();
+            let mut nodes = args.pop().unwrap_or_default();
+            nodes.push(MathNode::Subscript(script));
+            nodes
+        }
+        "msubsup" | "munderover" => {
+            let mut args = arguments(element, 3)?;
+            let superscript = args.pop().unwrap_or_default();
+            let subscript = args.pop().unwrap_or_default();
+            let mut nodes = args.pop().unwrap_or_default();
+            nodes.push(MathNode::Subscript(subscript));
+            nodes.push(MathNode::Superscript(superscript));
+            nodes
+        }
+        "mfenced" => convert_fenced(element)?,
+        "mtable" => convert_table(element)?,
+        name => return Err(error(element, format!("unsupported element <{}>", name))),
+    })
+}
+
+/// `<math>…</math>` 문자열을 읽어 수식 구조로 바꾼다.
+pub fn parse_mathml(s: &str) -> Result<Vec<MathNode>, BraillifyError> {
+    let mut nodes = vec![];
+    for node in parse_xml(s)? {
+        match node {
+            XmlNode::Element(element) => nodes.extend(convert(&element)?),
+            XmlNode::Text(text) if text.trim().is_empty() => {}
+            XmlNode::Text(text) => {
+                return Err(BraillifyError::MathParseError {
+                    input: s.to_string(),
+                    error: format!("unexpected text '{}'", text.trim()),
+                });
+            }
+        }
+    }
+    Ok(nodes)
+}
+
+pub fn encode_mathml(s: &str) -> Result<Vec<u8>, BraillifyError> {
+    encode_math(&parse_mathml(s)?)
+}
+
+/// 단어 안의 `<math>…</math>` 부분을 찾아 (앞부분, 수식, 뒷부분)으로 나눈다.
+pub fn split_math_element(word: &str) -> Option<(&str, &str, &str)> {
+    let start = word.find(MATH_OPEN)?;
+    // <mathfoo> 같은 다른 태그는 제외한다.
+    let after_name = word[start + MATH_OPEN.len()..].chars().next()?;
+    if !(after_name == '>' || after_name == '/' || after_name.is_whitespace()) {
+        return None;
+    }
+    let end = start + word[start..].find(MATH_CLOSE)? + MATH_CLOSE.len();
+    Some((&word[..start], &word[start..end], &word[end..]))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(s: &str) -> String {
+        encode_mathml(s)
+            .unwrap()
+            .iter()
+            .map(|c| encode_unicode(*c))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_encode_mathml() {
+        assert_eq!(
+            to_unicode("<math><msup><mi>x</mi><mn>2</mn></msup></math>"),
+            "⠭⠘⠼⠃"
+        );
+        assert_eq!(
+            to_unicode("<math><mfrac><mn>3</mn><mn>4</mn></mfrac></math>"),
+            "⠼⠙⠌⠼⠉"
+        );
+        assert_eq!(
+            to_unicode(
+                "<math><mfrac><mrow><mi>a</mi><mo>+</mo><mi>b</mi></mrow><mi>c</mi></mfrac></math>"
+            ),
+            "⠉⠌⠦⠄⠁⠢⠃⠠⠴"
+        );
+        assert_eq!(to_unicode("<math><msqrt><mn>2</mn></msqrt></math>"), "⠜⠼⠃⠻");
+        assert_eq!(
+            to_unicode("<math><mroot><mn>8</mn><mn>3</mn></mroot></math>"),
+            "⠘⠼⠉⠜⠼⠓⠻"
+        );
+        assert_eq!(
+            to_unicode("<math><msub><mi>a</mi><mn>1</mn></msub></math>"),
+            "⠁⠰⠼⠁"
+        );
+        assert_eq!(
+            to_unicode(
+                "<math><mn>2</mn><mo>&InvisibleTimes;</mo><mi>x</mi><mo>-</mo><mn>1</mn></math>"
+            ),
+            "⠼⠃⠭⠔⠼⠁"
+        );
+        assert_eq!(
+            to_unicode("<math><mfenced><mi>a</mi><mi>b</mi></mfenced></math>"),
+            "⠦⠄⠁⠐⠃⠠⠴"
+        );
+        assert_eq!(to_unicode("<math><mi>sin</mi><mi>θ</mi></math>"), "⠎⠊⠝⠀⠨⠹");
+        assert_eq!(
+            to_unicode(
+                "<math><mtable><mtr><mtd><mn>1</mn></mtd><mtd><mn>2</mn></mtd></mtr><mtr><mtd><mn>3</mn></mtd><mtd><mn>4</mn></mtd></mtr></mtable></math>"
+            ),
+            "⠼⠁⠀⠼⠃\n⠼⠉⠀⠼⠙"
+        );
+    }
+
+    #[test]
+    pub fn test_encode_mathml_accent() {
+        assert_eq!(
+            to_unicode("<math><mover><mi>x</mi><mo>¯</mo></mover></math>"),
+            "⠐⠭⠣⠱⠻"
+        );
+        assert_eq!(
+            to_unicode(
+                "<math><mover accent=\"true\"><mrow><mi>A</mi><mi>B</mi></mrow><mo>→</mo></mover></math>"
+            ),
+            "⠐⠠⠁⠠⠃⠣⠒⠒⠕⠻"
+        );
+        assert_eq!(
+            to_unicode("<math><munder><mi>x</mi><mo>_</mo></munder></math>"),
+            "⠐⠭⠩⠱⠻"
+        );
+        // 악센트가 아닌 mover, munder는 첨자로 읽는다.
+        assert_eq!(
+            to_unicode("<math><mover accent=\"false\"><mi>x</mi><mo>~</mo></mover></math>"),
+            "⠭⠘⠈⠔"
+        );
+        assert_eq!(
+            to_unicode(
+                "<math><munder><mi>lim</mi><mrow><mi>x</mi><mo>→</mo><mn>0</mn></mrow></munder></math>"
+            ),
+            "⠇⠊⠍⠰⠦⠄⠭⠒⠒⠕⠼⠚⠠⠴"
+        );
+    }
+
+    #[test]
+    pub fn test_encode_mathml_error() {
+        assert!(encode_mathml("<math><mfrac><mn>1</mn></mfrac></math>").is_err());
+        assert!(encode_mathml("<math><mfoo/></math>").is_err());
+        assert!(encode_mathml("<math><mi>x</mi>").is_err());
+    }
+
+    #[test]
+    pub fn test_split_math_element() {
+        assert_eq!(
+            split_math_element("값은<math><mn>1</mn></math>이다."),
+            Some(("값은", "<math><mn>1</mn></math>", "이다."))
+        );
+        assert_eq!(split_math_element("<mathx></mathx>"), None);
+        assert_eq!(split_math_element("<math><mn>1</mn>"), None);
+    }
+}
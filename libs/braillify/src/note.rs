@@ -0,0 +1,6 @@
+use crate::unicode::decode_unicode;
+
+/// 점역자 주 시작표
+pub const NOTE_START: [u8; 2] = [decode_unicode('⠸'), decode_unicode('⠦')];
+/// 점역자 주 종료표
+pub const NOTE_END: [u8; 2] = [decode_unicode('⠴'), decode_unicode('⠇')];
@@ -0,0 +1,357 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{math_symbol_shortcut, unicode::decode_unicode};
+
+/// 단어 안에서 숫자 표현이 될 수 있는 부분
+static CANDIDATE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[+\-]?\d(?:[\d,.:/~\-]*\d)?\.?(?:×10(?:[⁺⁻]?[⁰¹²³⁴⁵⁶⁷⁸⁹]+|[⁺⁻]))?")
+        .expect("Failed to compile CANDIDATE_REGEX")
+});
+
+/// 분류 규칙. 위에서부터 차례로 맞춰 본다.
+static RULES: Lazy<Vec<(Regex, NumericKind, Option<&'static str>)>> = Lazy::new(|| {
+    [
+        (r"^\d+$", NumericKind::Integer, None),
+        (
+            r"^\d{1,3}(?:,\d{3})+(?:\.\d+)?$",
+            NumericKind::Grouped,
+            None,
+        ),
+        (r"^\d+\.\d+$", NumericKind::Decimal, None),
+        (r"^-\d+(?:[.,]\d+)*$", NumericKind::Negative, None),
+        (
+            r"^-?\d+(?:\.\d+)?×10(?:[⁺⁻]?[⁰¹²³⁴⁵⁶⁷⁸⁹]+|[⁺⁻])$",
+            NumericKind::Scientific,
+            None,
+        ),
+        (r"^\+\d{1,3}(?:-\d{1,4}){2,3}$", NumericKind::Phone, None),
+        (
+            r"^0\d{1,2}-\d{3,4}-\d{4}(?:~\d+)?$",
+            NumericKind::Phone,
+            None,
+        ),
+        (
+            r"^\d{4}([-./])\d{1,2}([-./])\d{1,2}\.?$",
+            NumericKind::Date,
+            None,
+        ),
+        (
+            r"^(?:[01]?\d|2[0-4]):[0-5]\d(?::[0-5]\d)?$",
+            NumericKind::Time,
+            None,
+        ),
+        (
+            r"^(?:[01]?\d|2[0-4]):[0-5]\d[~\-](?:[01]?\d|2[0-4]):[0-5]\d$",
+            NumericKind::Range,
+            None,
+        ),
+        (
+            r"^-?\d+(?:[.,]\d+)*~-?\d+(?:[.,]\d+)*$",
+            NumericKind::Range,
+            None,
+        ),
+        (r"^\d+:\d+$", NumericKind::Ratio, None),
+        (
+            r"^\d{1,2}/\d{1,2}$",
+            NumericKind::Fraction,
+            Some("분수와 날짜(월/일) 중 어느 것인지 확실하지 않습니다"),
+        ),
+        (
+            r"^\d+-\d+$",
+            NumericKind::Hyphenated,
+            Some("범위와 붙임표로 이은 번호 중 어느 것인지 확실하지 않습니다"),
+        ),
+        (
+            r"^\d{1,3}(?:\.\d{1,3}){2,}\.?$",
+            NumericKind::Dotted,
+            Some("날짜와 버전 번호 중 어느 것인지 확실하지 않습니다"),
+        ),
+        (r"^\d+\.$", NumericKind::Integer, None),
+    ]
+    .into_iter()
+    .map(|(pattern, kind, warning)| {
+        (
+            Regex::new(pattern).expect("Failed to compile numeric rule"),
+            kind,
+            warning,
+        )
+    })
+    .collect()
+});
+
+/// 띄어 쓴 연월일(2024. 10. 17.)의 연, 월, 일. 월과 일 뒤에는 조사나 괄호가 붙을 수 있다.
+static SPACED_DATE_PARTS: Lazy<[Regex; 3]> = Lazy::new(|| {
+    [
+        r"^\d{4}\.$",
+        r"^(?:0?[1-9]|1[0-2])\.(?:\D|$)",
+        r"^(?:0?[1-9]|[12]\d|3[01])\.(?:\D|$)",
+    ]
+    .map(|pattern| Regex::new(pattern).expect("Failed to compile SPACED_DATE_PARTS"))
+});
+
+/// 숫자 표현의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericKind {
+    /// 123
+    Integer,
+    /// 1,000,000
+    Grouped,
+    /// 3.14
+    Decimal,
+    /// -3
+    Negative,
+    /// 3.2×10⁵, 1.5×10⁻³
+    Scientific,
+    /// 010-1234-5678, +82-10-1234-5678
+    Phone,
+    /// 2024-10-17, 2024.10.17., 2024. 10. 17.
+    Date,
+    /// 12:30, 12:30:45
+    Time,
+    /// 10~20, -10~-5, 09:00~18:00, 12:30-13:00
+    Range,
+    /// 3:2
+    Ratio,
+    /// 1/2
+    Fraction,
+    /// 1-2
+    Hyphenated,
+    /// 1.2.3
+    Dotted,
+    /// 어느 규칙에도 맞지 않는 숫자 표현
+    Unknown,
+}
+
+/// 단어 안의 숫자 표현 하나. 위치는 글자(char) 단위이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericSpan {
+    pub start: usize,
+    pub end: usize,
+    pub kind: NumericKind,
+    pub text: String,
+    /// 분류가 확실하지 않을 때의 경고
+    pub warning: Option<&'static str>,
+}
+
+impl NumericSpan {
+    pub fn contains(&self, index: usize) -> bool {
+        self.start <= index && index < self.end
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.text.chars().nth(index.checked_sub(self.start)?)
+    }
+
+    /// 붙임표(-)가 빼기표가 아니라 붙임표로 쓰이는 표현인지 확인한다.
+    pub fn uses_hyphen(&self) -> bool {
+        matches!(
+            self.kind,
+            NumericKind::Phone | NumericKind::Date | NumericKind::Range
+        )
+    }
+
+    /// 연산 기호처럼 보이지만 숫자의 일부여서 앞뒤를 띄어 쓰지 않는 기호인지 확인한다.
+    /// 음수와 범위 양 끝의 부호, 지수 표기의 ×, 국제 전화번호 앞의 +가 그렇다.
+    pub fn is_attached_symbol(&self, index: usize) -> bool {
+        let Some(c) = self.char_at(index) else {
+            return false;
+        };
+        let is_first = index == self.start;
+        match self.kind {
+            NumericKind::Negative => is_first,
+            NumericKind::Range => c == '-' && (is_first || self.char_at(index - 1) == Some('~')),
+            NumericKind::Scientific => (c == '-' && is_first) || c == '×',
+            NumericKind::Phone => c == '+' && is_first,
+            _ => false,
+        }
+    }
+}
+
+/// 지수의 부호(⁺, ⁻)는 윗첨자표 뒤에 더하기표나 빼기표를 적고, 지수가 이어지면 수표와 숫자를 적는다.
+/// (10⁻³ → ⠼⠁⠚⠘⠔⠼⠉) 적은 점자와 읽은 글자 수를 돌려준다.
+pub fn encode_exponent_sign(chars: &[char]) -> Option<(Vec<u8>, usize)> {
+    let sign = match chars.first()? {
+        '⁺' => decode_unicode('⠢'),
+        '⁻' => decode_unicode('⠔'),
+        _ => return None,
+    };
+    let mut result = vec![decode_unicode('⠘'), sign];
+    let mut len = 1;
+    if let Some((code, digits)) = math_symbol_shortcut::encode_script_digits(&chars[1..]) {
+        result.extend(&code[1..]);
+        len += digits;
+    }
+    Some((result, len))
+}
+
+pub fn classify(text: &str) -> (NumericKind, Option<&'static str>) {
+    RULES
+        .iter()
+        .find(|(regex, _, _)| regex.is_match(text))
+        .map(|(_, kind, warning)| (*kind, *warning))
+        .unwrap_or((NumericKind::Unknown, Some("알 수 없는 숫자 형식입니다")))
+}
+
+/// 띄어 쓴 연월일(2024. 10. 17.)이 `word`에서 시작하면 날짜를 이루는 단어 수를 돌려준다.
+/// 단어마다 따로 분류하면 세 개의 수가 되므로 이어지는 단어를 함께 본다. 연과 월만 적은 날짜(2024. 10.)도 날짜이다.
+pub fn recognize_spaced_date(word: &str, following: &[&str]) -> Option<usize> {
+    let [year, month, day] = &*SPACED_DATE_PARTS;
+    if !year.is_match(word) {
+        return None;
+    }
+    let month_word = following.first().filter(|w| month.is_match(w))?;
+    if month_word.ends_with('.') && following.get(1).is_some_and(|w| day.is_match(w)) {
+        Some(3)
+    } else {
+        Some(2)
+    }
+}
+
+/// 단어 안의 숫자 표현을 모두 찾아 분류한다.
+pub fn recognize(word: &str) -> Vec<NumericSpan> {
+    // 등호 등 다른 연산 기호가 있는 식에서는 `-`를 빼기표로 확실히 읽을 수 있다.
+    let is_expression = word.contains(['=', '+', '×', '÷', '<', '>']);
+    let mut spans = vec![];
+    for m in CANDIDATE_REGEX.find_iter(word) {
+        let mut text = m.as_str();
+        let mut byte_start = m.start();
+        // 앞의 글자에 이어지는 +, -는 부호가 아니라 연산 기호이다. (3+4, x-1)
+        if text.starts_with(['+', '-'])
+            && word[..byte_start]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || c == ')')
+        {
+            text = &text[1..];
+            byte_start += 1;
+        }
+        let (kind, mut warning) = classify(text);
+        if is_expression && kind == NumericKind::Hyphenated {
+            warning = None;
+        }
+        let start = word[..byte_start].chars().count();
+        spans.push(NumericSpan {
+            start,
+            end: start + text.chars().count(),
+            kind,
+            text: text.to_string(),
+            warning,
+        });
+    }
+    spans
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_classify() {
+        let kind = |s: &str| classify(s).0;
+        assert_eq!(kind("123"), NumericKind::Integer);
+        assert_eq!(kind("1,000,000"), NumericKind::Grouped);
+        assert_eq!(kind("3.14"), NumericKind::Decimal);
+        assert_eq!(kind("-3"), NumericKind::Negative);
+        assert_eq!(kind("3.2×10⁵"), NumericKind::Scientific);
+        assert_eq!(kind("-1.5×10⁻³"), NumericKind::Scientific);
+        assert_eq!(kind("1.5×10⁻"), NumericKind::Scientific);
+        assert_eq!(kind("+82-10-1234-5678"), NumericKind::Phone);
+        assert_eq!(kind("010-1234-5678"), NumericKind::Phone);
+        assert_eq!(kind("02-2669-9775"), NumericKind::Phone);
+        assert_eq!(kind("02-2669-9775~6"), NumericKind::Phone);
+        assert_eq!(kind("2024-10-17"), NumericKind::Date);
+        assert_eq!(kind("2024.10.17."), NumericKind::Date);
+        assert_eq!(kind("12:30"), NumericKind::Time);
+        assert_eq!(kind("12:30:45"), NumericKind::Time);
+        assert_eq!(kind("09:00~18:00"), NumericKind::Range);
+        assert_eq!(kind("10~20"), NumericKind::Range);
+        assert_eq!(kind("-10~-5"), NumericKind::Range);
+        assert_eq!(kind("12:30-13:00"), NumericKind::Range);
+        assert_eq!(kind("3:2"), NumericKind::Ratio);
+        assert_eq!(kind("30:15"), NumericKind::Ratio);
+        assert_eq!(kind("1/2"), NumericKind::Fraction);
+        assert_eq!(kind("1-2"), NumericKind::Hyphenated);
+        assert_eq!(kind("1.2.3"), NumericKind::Dotted);
+        assert_eq!(kind("1/2/3/4"), NumericKind::Unknown);
+    }
+
+    #[test]
+    pub fn test_classify_warning() {
+        assert!(classify("1-2").1.is_some());
+        assert!(classify("1/2").1.is_some());
+        assert!(classify("12:30").1.is_none());
+        assert!(classify("2024-10-17").1.is_none());
+    }
+
+    #[test]
+    pub fn test_recognize() {
+        let spans = recognize("+82-10-1234-5678");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].kind, NumericKind::Phone);
+        assert!(spans[0].uses_hyphen());
+
+        let spans = recognize("3+4=7");
+        assert_eq!(
+            spans.iter().map(|s| s.kind).collect::<Vec<_>>(),
+            vec![NumericKind::Integer; 3]
+        );
+        assert_eq!(spans[1].start, 2);
+
+        let spans = recognize("5-3=2");
+        assert_eq!(spans[0].kind, NumericKind::Hyphenated);
+        assert!(spans[0].warning.is_none());
+        assert!(recognize("1-2")[0].warning.is_some());
+
+        let spans = recognize("12:30-13:00");
+        assert!(spans[0].uses_hyphen());
+        assert!(!spans[0].is_attached_symbol(5));
+
+        let spans = recognize("영업시간은09:00~18:00이다");
+        assert_eq!(spans[0].kind, NumericKind::Range);
+        assert_eq!((spans[0].start, spans[0].end), (5, 16));
+    }
+
+    #[test]
+    pub fn test_is_attached_symbol() {
+        let attached = |word: &str| {
+            let spans = recognize(word);
+            (0..word.chars().count())
+                .filter(|i| spans.iter().any(|s| s.is_attached_symbol(*i)))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(attached("-5개"), vec![0]);
+        assert_eq!(attached("-10~-5"), vec![0, 4]);
+        assert_eq!(attached("-3.2×10⁵"), vec![0, 4]);
+        assert_eq!(attached("+82-10-1234-5678"), vec![0]);
+        assert!(attached("5-3=2").is_empty());
+        assert!(attached("3+4").is_empty());
+        assert!(attached("2×3").is_empty());
+    }
+
+    #[test]
+    pub fn test_recognize_spaced_date() {
+        assert_eq!(recognize_spaced_date("2024.", &["10.", "17."]), Some(3));
+        assert_eq!(recognize_spaced_date("2024.", &["10.", "17.(목)"]), Some(3));
+        assert_eq!(recognize_spaced_date("2024.", &["10.", "사과"]), Some(2));
+        assert_eq!(recognize_spaced_date("2024.", &["10.에"]), Some(2));
+        assert_eq!(recognize_spaced_date("2024.", &["13.", "1."]), None);
+        assert_eq!(recognize_spaced_date("2024.", &["3.5%"]), None);
+        assert_eq!(recognize_spaced_date("10.", &["17."]), None);
+        assert_eq!(recognize_spaced_date("2024.", &[]), None);
+    }
+
+    #[test]
+    pub fn test_encode_exponent_sign() {
+        assert_eq!(
+            encode_exponent_sign(&['⁻', '¹', '²']).unwrap(),
+            (vec![24, 20, 60, 1, 3], 3)
+        );
+        assert_eq!(encode_exponent_sign(&['⁻']).unwrap(), (vec![24, 20], 1));
+        assert_eq!(
+            encode_exponent_sign(&['⁺', 'x']).unwrap(),
+            (vec![24, 34], 1)
+        );
+        assert!(encode_exponent_sign(&['²']).is_none());
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+
+use crate::{
+    archive::{Archive, find_all},
+    book::{Block, BookConfig, Volume, format_book},
+    error::BraillifyError,
+    rich::{Run, math_run, note_paragraph, push_text, trim_runs},
+    table::{Table, TableCell},
+    xml::{XmlElement, XmlNode, parse_xml},
+};
+
+/// `<style:style>`의 글자 서식. 지정하지 않은 속성은 부모 스타일을 따른다.
+#[derive(Default)]
+struct Style {
+    parent: Option<String>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underline: Option<bool>,
+    strike: Option<bool>,
+}
+
+fn styles(nodes: &[XmlNode], styles: &mut HashMap<String, Style>) {
+    for style in find_all(nodes, "style") {
+        let Some(name) = style.attribute("name") else {
+            continue;
+        };
+        let properties = style
+            .elements()
+            .find(|e| e.local_name() == "text-properties");
+        let property =
+            |name: &str, on: fn(&str) -> bool| properties.and_then(|pr| pr.attribute(name)).map(on);
+        styles.insert(
+            name.to_string(),
+            Style {
+                parent: style.attribute("parent-style-name").map(str::to_string),
+                bold: property("font-weight", |v| {
+                    v == "bold" || v.parse::<u32>().is_ok_and(|weight| weight >= 600)
+                }),
+                italic: property("font-style", |v| v == "italic" || v == "oblique"),
+                underline: property("text-underline-style", |v| v != "none"),
+                strike: property("text-line-through-style", |v| v != "none"),
+            },
+        );
+    }
+}
+
+/// `<text:list-style>`에서 번호를 매기는 목록 수준과 그 첫 번호(`text:start-value`)를 찾는다.
+fn numbered_lists(nodes: &[XmlNode]) -> HashMap<(String, usize), Option<u64>> {
+    let mut lists = HashMap::new();
+    for list in find_all(nodes, "list-style") {
+        let Some(name) = list.attribute("name") else {
+            continue;
+        };
+        for level in list.elements() {
+            let Some(depth) = level
+                .attribute("level")
+                .and_then(|l| l.parse::<usize>().ok())
+            else {
+                continue;
+            };
+            let numbered = level.local_name() == "list-level-style-number"
+                && level.attribute("num-format").is_some_and(|f| !f.is_empty());
+            let start = numbered.then(|| start_value(level).unwrap_or(1));
+            lists.insert((name.to_string(), depth.saturating_sub(1)), start);
+        }
+    }
+    lists
+}
+
+fn start_value(element: &XmlElement) -> Option<u64> {
+    element.attribute("start-value")?.trim().parse().ok()
+}
+
+/// 표 칸 안의 글을 서식 없이 이어 붙인다.
+fn plain_text(element: &XmlElement) -> String {
+    element
+        .elements()
+        .filter(|e| matches!(e.local_name(), "p" | "h"))
+        .map(XmlElement::text)
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 머리글 행 묶음(`<table:table-header-rows>`)을 포함해 표의 행을 차례로 모은다.
+fn table_rows<'a>(element: &'a XmlElement, header: bool, rows: &mut Vec<(bool, &'a XmlElement)>) {
+    for child in element.elements() {
+        match child.local_name() {
+            "table-row" => rows.push((header, child)),
+            "table-header-rows" => table_rows(child, true, rows),
+            "table-rows" | "table-row-group" => table_rows(child, header, rows),
+            _ => {}
+        }
+    }
+}
+
+fn parse_table(element: &XmlElement) -> Table {
+    let mut rows = vec![];
+    table_rows(element, false, &mut rows);
+    let mut table = Table::default();
+    let mut in_header = true;
+    for (header, row) in rows {
+        if in_header && header {
+            table.header_rows += 1;
+        } else {
+            in_header = false;
+        }
+        table.rows.push(
+            row.elements()
+                // 병합으로 가려진 칸(`covered-table-cell`)은 앞 칸의 범위에 들어간다.
+                .filter(|e| e.local_name() == "table-cell")
+                .map(|cell| {
+                    let span = |name: &str| {
+                        cell.attribute(name)
+                            .and_then(|span| span.parse().ok())
+                            .filter(|span| *span > 0)
+                            .unwrap_or(1)
+                    };
+                    TableCell {
+                        row_span: span("number-rows-spanned"),
+                        col_span: span("number-columns-spanned"),
+                        ..TableCell::new(plain_text(cell))
+                    }
+                })
+                .collect(),
+        );
+    }
+    table
+}
+
+/// `content.xml`의 본문을 `Block`으로 모은다.
+struct Builder<'a> {
+    archive: Archive<'a>,
+    blocks: Vec<Block>,
+    styles: HashMap<String, Style>,
+    numbered_lists: HashMap<(String, usize), Option<u64>>,
+}
+
+impl Builder<'_> {
+    /// 부모 스타일을 따라 올라가며 정해지지 않은 서식을 채운다.
+    fn style(&self, name: Option<&str>, base: &Run) -> Run {
+        let mut run = base.clone();
+        let (mut bold, mut italic, mut underline, mut strike) = (None, None, None, None);
+        let mut name = name;
+        // 스타일이 서로를 부모로 가리켜도 멈추도록 단계를 제한한다.
+        for _ in 0..16 {
+            let Some(style) = name.and_then(|name| self.styles.get(name)) else {
+                break;
+            };
+            bold = bold.or(style.bold);
+            italic = italic.or(style.italic);
+            underline = underline.or(style.underline);
+            strike = strike.or(style.strike);
+            name = style.parent.as_deref();
+        }
+        run.bold = bold.unwrap_or(run.bold);
+        run.italic = italic.unwrap_or(run.italic);
+        run.underline = underline.unwrap_or(run.underline);
+        run.strike = strike.unwrap_or(run.strike);
+        run
+    }
+
+    /// 문단, 제목, 목록, 표가 오는 곳
+    fn body(&mut self, nodes: &[XmlNode]) {
+        for node in nodes {
+            let XmlNode::Element(element) = node else {
+                continue;
+            };
+            match element.local_name() {
+                "h" => {
+                    let level = element
+                        .attribute("outline-level")
+                        .and_then(|level| level.parse::<u8>().ok())
+                        .unwrap_or(1)
+                        .clamp(1, 6);
+                    self.paragraph(element, Some(level), None);
+                }
+                "p" => self.paragraph(element, None, None),
+                "list" => self.list(
+                    element,
+                    element.attribute("style-name").unwrap_or_default(),
+                    0,
+                ),
+                "table" => self.blocks.push(Block::Table(parse_table(element))),
+                "section" => self.body(&element.children),
+                _ => {}
+            }
+        }
+    }
+
+    fn list(&mut self, list: &XmlElement, style: &str, depth: usize) {
+        let start = self
+            .numbered_lists
+            .get(&(style.to_string(), depth))
+            .copied()
+            .flatten();
+        let mut next = start.unwrap_or(1);
+        for item in list.elements() {
+            // 목록 머리(list-header)에는 번호를 매기지 않는다.
+            let number = match item.local_name() {
+                "list-item" => {
+                    let number = start_value(item).unwrap_or(next);
+                    next = number + 1;
+                    start.map(|_| number)
+                }
+                "list-header" => None,
+                _ => continue,
+            };
+            let mut first = true;
+            for child in item.elements() {
+                match child.local_name() {
+                    "p" | "h" => {
+                        // 번호는 항목의 첫 문단에만 붙인다.
+                        let prefix = number.filter(|_| first).map(|number| format!("{number}. "));
+                        first = false;
+                        self.paragraph(child, None, Some((depth, prefix)));
+                    }
+                    "list" => {
+                        let style = child.attribute("style-name").unwrap_or(style).to_string();
+                        self.list(child, &style, depth + 1);
+                    }
+                    "table" => self.blocks.push(Block::Table(parse_table(child))),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn paragraph(
+        &mut self,
+        p: &XmlElement,
+        heading: Option<u8>,
+        item: Option<(usize, Option<String>)>,
+    ) {
+        let base = self.style(p.attribute("style-name"), &Run::default());
+        let mut runs = vec![];
+        if let Some((_, Some(prefix))) = &item {
+            runs.push(Run::new(prefix.clone()));
+        }
+        let mut notes = vec![];
+        self.inline(&p.children, &base, &mut runs, &mut notes);
+        let runs = trim_runs(runs);
+        if !runs.is_empty() {
+            self.blocks.push(match (heading, item) {
+                (Some(level), _) => Block::Heading { level, runs },
+                (None, Some((depth, _))) => Block::ListItem { depth, runs },
+                (None, None) => Block::Paragraph(runs),
+            });
+        }
+        for (citation, runs) in notes {
+            self.blocks.push(note_paragraph(citation, runs));
+        }
+    }
+
+    fn inline(
+        &mut self,
+        nodes: &[XmlNode],
+        style: &Run,
+        runs: &mut Vec<Run>,
+        notes: &mut Vec<(String, Vec<Run>)>,
+    ) {
+        for node in nodes {
+            let element = match node {
+                XmlNode::Text(text) => {
+                    push_text(runs, text, style);
+                    continue;
+                }
+                XmlNode::Element(element) => element,
+            };
+            match element.local_name() {
+                "span" => {
+                    let style = self.style(element.attribute("style-name"), style);
+                    self.inline(&element.children, &style, runs, notes);
+                }
+                "a" | "meta" | "ruby-base" => self.inline(&element.children, style, runs, notes),
+                "s" => {
+                    let count = element
+                        .attribute("c")
+                        .and_then(|c| c.parse().ok())
+                        .unwrap_or(1);
+                    push_text(runs, &" ".repeat(count), style);
+                }
+                "tab" => push_text(runs, " ", style),
+                "line-break" => push_text(runs, "\n", style),
+                "note" => {
+                    let citation = element
+                        .elements()
+                        .find(|e| e.local_name() == "note-citation")
+                        .map(|c| c.text().trim().to_string())
+                        .unwrap_or_default();
+                    push_text(runs, &format!("{citation})"), &Run::default());
+                    let mut note = vec![];
+                    if let Some(body) = element.elements().find(|e| e.local_name() == "note-body") {
+                        for p in body
+                            .elements()
+                            .filter(|e| matches!(e.local_name(), "p" | "h"))
+                        {
+                            if !note.is_empty() {
+                                push_text(&mut note, " ", &Run::default());
+                            }
+                            let base = self.style(p.attribute("style-name"), &Run::default());
+                            self.inline(&p.children, &base, &mut note, &mut vec![]);
+                        }
+                    }
+                    notes.push((citation, note));
+                }
+                "frame" => runs.extend(self.frame(element)),
+                _ => {}
+            }
+        }
+    }
+
+    /// 수식 개체(`<draw:object>`)와 그림(`<draw:image>`)을 담은 틀
+    fn frame(&mut self, frame: &XmlElement) -> Option<Run> {
+        let note = |text: &str| Run {
+            note: true,
+            ..Run::new(text)
+        };
+        let title = frame
+            .elements()
+            .find(|e| matches!(e.local_name(), "desc" | "title"))
+            .map(|e| e.text().trim().to_string())
+            .filter(|text| !text.is_empty());
+        for child in frame.elements() {
+            match child.local_name() {
+                "object" => {
+                    let path = child
+                        .attribute("href")
+                        .unwrap_or_default()
+                        .trim_start_matches("./");
+                    let path = format!("{path}/content.xml");
+                    let xml = if self.archive.contains(&path) {
+                        self.archive.read(&path).ok()
+                    } else {
+                        None
+                    };
+                    let math = xml
+                        .and_then(|xml| parse_xml(&xml).ok())
+                        .and_then(|nodes| find_all(&nodes, "math").first().map(|m| (*m).clone()));
+                    return Some(match math {
+                        Some(math) => {
+                            // StarMath 주석이 있으면 옮기지 못한 수식의 글로 쓴다.
+                            let fallback = find_all(&math.children, "annotation")
+                                .first()
+                                .map(|annotation| annotation.text())
+                                .unwrap_or_else(|| math.text());
+                            math_run(&math.to_xml(), &fallback)
+                        }
+                        None => note(title.as_deref().unwrap_or("개체 생략")),
+                    });
+                }
+                "image" => return Some(note(title.as_deref().unwrap_or("그림 생략"))),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// ODT(OpenDocument 텍스트) 문서를 본문 순서대로 읽어 점자 책의 덩어리로 나눈다.
+/// 개요 수준이 있는 제목, 목록, 표, 각주와 수식 개체를 읽는다.
+pub fn parse_odt(bytes: &[u8]) -> Result<Vec<Block>, BraillifyError> {
+    let mut archive = Archive::new(bytes, "ODT")?;
+    let content = parse_xml(&archive.read("content.xml")?)?;
+    let mut style_map = HashMap::new();
+    let mut lists = HashMap::new();
+    // 공통 스타일을 먼저 읽고 문서의 자동 스타일로 덮어쓴다.
+    if archive.contains("styles.xml") {
+        let nodes = parse_xml(&archive.read("styles.xml")?)?;
+        styles(&nodes, &mut style_map);
+        lists.extend(numbered_lists(&nodes));
+    }
+    styles(&content, &mut style_map);
+    lists.extend(numbered_lists(&content));
+
+    let text = find_all(&content, "body")
+        .first()
+        .and_then(|body| body.elements().find(|e| e.local_name() == "text"))
+        .cloned()
+        .ok_or_else(|| archive.error("office:text not found"))?;
+    let mut builder = Builder {
+        archive,
+        blocks: vec![],
+        styles: style_map,
+        numbered_lists: lists,
+    };
+    builder.body(&text.children);
+    Ok(builder.blocks)
+}
+
+/// ODT 문서를 점자 책으로 배치한다.
+pub fn encode_odt(bytes: &[u8], config: &BookConfig) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_odt(bytes)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::archive::build_zip;
+
+    const CONTENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:draw="urn:oasis:names:tc:opendocument:xmlns:drawing:1.0" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:svg="urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0">
+<office:automatic-styles>
+  <style:style style:name="T1" style:family="text"><style:text-properties fo:font-weight="bold"/></style:style>
+  <style:style style:name="T2" style:family="text" style:parent-style-name="Emphasis"><style:text-properties style:text-underline-style="solid"/></style:style>
+  <text:list-style style:name="L1">
+    <text:list-level-style-number text:level="1" style:num-suffix="." style:num-format="1"/>
+    <text:list-level-style-bullet text:level="2" text:bullet-char="•"/>
+  </text:list-style>
+</office:automatic-styles>
+<office:body><office:text>
+  <text:sequence-decls/>
+  <text:h text:style-name="Heading_20_1" text:outline-level="1">제목</text:h>
+  <text:p text:style-name="Standard">서울은<text:s/><text:span text:style-name="T1">수도</text:span><text:span text:style-name="T2">이다</text:span><text:note text:id="ftn1" text:note-class="footnote"><text:note-citation>1</text:note-citation><text:note-body><text:p text:style-name="Footnote">대한민국의 수도</text:p></text:note-body></text:note>.</text:p>
+  <text:list text:style-name="L1">
+    <text:list-header><text:p>과일</text:p></text:list-header>
+    <text:list-item><text:p>사과</text:p><text:list><text:list-item><text:p>부사</text:p></text:list-item></text:list></text:list-item>
+    <text:list-item><text:p>배</text:p></text:list-item>
+    <text:list-item text:start-value="5"><text:p>감</text:p></text:list-item>
+  </text:list>
+  <text:p>값은 <draw:frame draw:name="Object1"><draw:object xlink:href="./Object 1" xlink:type="simple"/></draw:frame><draw:frame><draw:image xlink:href="Pictures/a.png"/><svg:desc>지도</svg:desc></draw:frame></text:p>
+  <table:table table:name="표1">
+    <table:table-column table:number-columns-repeated="2"/>
+    <table:table-header-rows><table:table-row><table:table-cell><text:p>과일</text:p></table:table-cell><table:table-cell><text:p>값</text:p></table:table-cell></table:table-row></table:table-header-rows>
+    <table:table-row><table:table-cell table:number-columns-spanned="2"><text:p>사과</text:p></table:table-cell><table:covered-table-cell/></table:table-row>
+  </table:table>
+</office:text></office:body>
+</office:document-content>"#;
+
+    const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-styles xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0">
+<office:styles>
+  <style:style style:name="Emphasis" style:family="text"><style:text-properties fo:font-style="italic"/></style:style>
+</office:styles>
+</office:document-styles>"#;
+
+    const OBJECT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<math xmlns="http://www.w3.org/1998/Math/MathML" display="block"><semantics><mfrac><mn>1</mn><mn>2</mn></mfrac><annotation encoding="StarMath 5.0">1 over 2</annotation></semantics></math>"#;
+
+    #[test]
+    pub fn test_parse_odt() {
+        let odt = build_zip(&[
+            ("mimetype", "application/vnd.oasis.opendocument.text"),
+            ("content.xml", CONTENT),
+            ("styles.xml", STYLES),
+            ("Object 1/content.xml", OBJECT),
+        ]);
+        let blocks = parse_odt(&odt).unwrap();
+        assert_eq!(
+            blocks[..8],
+            [
+                Block::heading(1, "제목"),
+                Block::Paragraph(vec![
+                    Run::new("서울은 "),
+                    Run {
+                        bold: true,
+                        ..Run::new("수도")
+                    },
+                    Run {
+                        underline: true,
+                        italic: true,
+                        ..Run::new("이다")
+                    },
+                    Run::new("1)."),
+                ]),
+                Block::paragraph("1) 대한민국의 수도"),
+                Block::list_item(0, "과일"),
+                Block::list_item(0, "1. 사과"),
+                Block::list_item(1, "부사"),
+                Block::list_item(0, "2. 배"),
+                Block::list_item(0, "5. 감"),
+            ]
+        );
+        let Block::Paragraph(runs) = &blocks[8] else {
+            panic!("{:?}", blocks[8]);
+        };
+        assert_eq!(runs.len(), 3);
+        assert!(runs[1].math && runs[1].text.contains("<mfrac>"));
+        assert_eq!(
+            runs[2],
+            Run {
+                note: true,
+                ..Run::new("지도")
+            }
+        );
+        assert_eq!(
+            blocks[9],
+            Block::Table(Table {
+                rows: vec![
+                    vec![TableCell::new("과일"), TableCell::new("값")],
+                    vec![TableCell {
+                        col_span: 2,
+                        ..TableCell::new("사과")
+                    }],
+                ],
+                header_rows: 1,
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_parse_odt_error() {
+        assert!(matches!(
+            parse_odt(&build_zip(&[("content.xml", "<office:document-content/>")])),
+            Err(BraillifyError::DocumentError { .. })
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use phf::{phf_map, phf_set};
+
+use crate::{char_struct::KoreanChar, utils::is_korean_char};
+
+/// 체언 뒤에 붙는 조사와 그 뒤에 다른 조사가 더 붙을 수 있는지 여부
+/// (에서 + 부터 + 는은 되지만 도 + 로, 가 + 도는 조사가 아니다.)
+static PARTICLES: phf::Map<&'static str, bool> = phf_map! {
+    "이" => false, "가" => false, "을" => false, "를" => false, "은" => false, "는" => false,
+    "의" => false, "도" => false, "요" => false, "이요" => false, "야" => false, "아" => false,
+    "이여" => false, "여" => false, "이나" => false, "나" => false, "이나마" => false,
+    "나마" => false, "이든지" => false, "든지" => false, "이든" => false, "든" => false,
+    "께서" => true, "에" => true, "에서" => true, "에게" => true, "에게서" => true, "께" => true,
+    "한테" => true, "한테서" => true, "더러" => true, "로" => true, "으로" => true,
+    "로서" => true, "으로서" => true, "로써" => true, "으로써" => true, "과" => true,
+    "와" => true, "하고" => true, "이랑" => true, "랑" => true, "만" => true, "뿐" => true,
+    "까지" => true, "부터" => true, "보다" => true, "처럼" => true, "같이" => true,
+    "만큼" => true, "마다" => true, "조차" => true, "마저" => true, "밖에" => true, "씩" => true,
+};
+
+/// 서술격 조사 ‘이다’와 ‘아니다’의 활용형
+static COPULAS: phf::Set<&'static str> = phf_set! {
+    "이다", "입니다", "입니까", "이고", "이며", "며", "이면", "면", "이므로", "이니까", "이니",
+    "인", "일", "임", "이었다", "였다", "이었고", "였고", "이에요", "예요", "이야", "이지",
+    "이지만", "이라", "라", "이라서", "라서", "이라고", "라고", "이라는", "라는", "이라면",
+    "라면", "이죠", "죠", "아니다", "아닌", "아니고", "아니면", "아니라", "아닙니다",
+};
+
+/// 제44항 [다만] 숫자와 혼동되는 첫소리 글자
+const DIGIT_LIKE_CHOSEONG: [char; 7] = ['ㄴ', 'ㄷ', 'ㅁ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'];
+
+/// 제44항 [다만] 숫자와 혼동되는 약자
+const DIGIT_LIKE_SYLLABLES: [char; 1] = ['운'];
+
+/// 조사는 최대 이 개수까지 겹쳐 쓸 수 있다고 본다. (에서 + 부터 + 는)
+const MAX_STACK: usize = 3;
+
+fn is_particle_stack(text: &str, depth: usize) -> bool {
+    if depth >= MAX_STACK {
+        return false;
+    }
+    text.char_indices()
+        .skip(1)
+        .map(|(idx, _)| idx)
+        .chain([text.len()])
+        .any(|end| {
+            let (head, rest) = text.split_at(end);
+            if COPULAS.contains(head) {
+                // 서술격 조사의 활용형 뒤에는 다른 조사가 붙지 않는다.
+                rest.is_empty()
+            } else {
+                match PARTICLES.get(head) {
+                    Some(_) if rest.is_empty() => true,
+                    Some(stackable) => *stackable && is_particle_stack(rest, depth + 1),
+                    None => false,
+                }
+            }
+        })
+}
+
+/// 한글 부분 전체가 앞말에 붙여 쓰는 조사(서술격 조사 포함)인지 확인한다.
+pub fn is_particle(text: &str) -> bool {
+    !text.is_empty() && is_particle_stack(text, 0)
+}
+
+/// 한글 바로 앞에 붙어 나온 말
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preceding {
+    Number,
+    English,
+    /// 문장 부호, 단위 등 연산 기호가 아닌 기호
+    Symbol,
+    /// 앞뒤를 띄어 쓰는 연산 기호 (+, −, ×, ÷, =)
+    Operator,
+}
+
+/// 숫자, 로마자, 기호 뒤에 붙어 나온 한글을 앞말과 띄어 쓸지 정한다.
+/// - 숫자 뒤: 숫자와 혼동되는 글자로 시작하면 조사라도 띄어 쓴다. (제44항 [다만])
+/// - 연산 기호 뒤: 조사이면 붙여 쓰고 낱말이면 띄어 쓴다. (제46항)
+/// - 로마자, 그 밖의 기호 뒤: 조사든 낱말이든 묵자대로 붙여 쓴다. (A4용지, Bravo!를)
+pub fn needs_space_before(preceding: Preceding, korean: &str) -> bool {
+    let Some(first) = korean.chars().next().filter(|c| is_korean_char(*c)) else {
+        return false;
+    };
+    match preceding {
+        Preceding::Number => {
+            DIGIT_LIKE_SYLLABLES.contains(&first)
+                || KoreanChar::new(first).is_ok_and(|c| DIGIT_LIKE_CHOSEONG.contains(&c.cho))
+        }
+        Preceding::Operator => !is_particle(korean),
+        Preceding::English | Preceding::Symbol => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_is_particle() {
+        for particle in [
+            "과",
+            "와",
+            "이다",
+            "하고",
+            "이랑",
+            "랑",
+            "아니다",
+            "은",
+            "에서",
+            "에서는",
+            "으로만",
+            "까지도",
+            "입니다",
+            "이라고",
+            "에게서부터",
+            "만을",
+            "뿐이다",
+            "로도",
+            "와는",
+        ] {
+            assert!(is_particle(particle), "{particle}");
+        }
+        for word in [
+            "",
+            "개",
+            "반지름",
+            "나룻배",
+            "학교",
+            "이다가",
+            "에서이다는",
+            "다리",
+            "도로",
+            "가지",
+            "가도",
+            "지도",
+            "고기",
+            "다만",
+            "는이다",
+        ] {
+            assert!(!is_particle(word), "{word}");
+        }
+    }
+
+    #[test]
+    pub fn test_needs_space_before() {
+        for korean in ["도", "톤", "운", "년", "명", "는"] {
+            assert!(needs_space_before(Preceding::Number, korean), "{korean}");
+        }
+        for korean in ["개", "월", "이다", "과"] {
+            assert!(!needs_space_before(Preceding::Number, korean), "{korean}");
+        }
+        assert!(needs_space_before(Preceding::Operator, "반지름"));
+        assert!(needs_space_before(Preceding::Operator, "도로"));
+        assert!(!needs_space_before(Preceding::Operator, "이다"));
+        assert!(!needs_space_before(Preceding::Operator, "과"));
+        assert!(!needs_space_before(Preceding::English, "용지"));
+        assert!(!needs_space_before(Preceding::English, "를"));
+        assert!(!needs_space_before(Preceding::Symbol, "도서관"));
+        assert!(!needs_space_before(Preceding::Number, ""));
+    }
+}
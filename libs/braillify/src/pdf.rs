@@ -0,0 +1,516 @@
+use std::{
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+};
+
+use once_cell::sync::Lazy;
+use pdf_extract::{Document, MediaBox, OutputDev, OutputError, Transform, output_doc};
+use regex::Regex;
+
+use crate::{
+    book::{Block, BookConfig, Volume, format_book},
+    error::BraillifyError,
+    rich::Run,
+};
+
+/// "1.", "가)", "(3)", "□", "-"처럼 목록 항목을 여는 머리
+static LIST_MARKER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\d+[.)]|[가-힣][.)]|\(\d+\)|\([가-힣]\)|[□■○●◦•∙·※\-–])\s")
+        .expect("Failed to compile LIST_MARKER")
+});
+
+/// PDF를 읽는 방법
+#[derive(Debug, Clone, Default)]
+pub struct PdfConfig {
+    /// 쪽마다 되풀이되는 머리글과 바닥글, 쪽 번호 줄을 지운다.
+    pub drop_headers_footers: bool,
+}
+
+fn error(error: impl ToString) -> BraillifyError {
+    BraillifyError::DocumentError {
+        format: "PDF".to_string(),
+        error: error.to_string(),
+    }
+}
+
+/// 쪽에 찍힌 글자 하나
+struct Glyph {
+    x: f64,
+    y: f64,
+    end: f64,
+    size: f64,
+    text: String,
+}
+
+/// 기준선이 같은 글자를 왼쪽부터 이어 붙인 줄
+#[derive(Debug, Clone)]
+struct Line {
+    x: f64,
+    y: f64,
+    end: f64,
+    size: f64,
+    text: String,
+}
+
+/// 쪽마다 글자의 자리를 모은다.
+#[derive(Default)]
+struct Collector {
+    pages: Vec<Vec<Glyph>>,
+}
+
+impl OutputDev for Collector {
+    fn begin_page(
+        &mut self,
+        _page_num: u32,
+        _media_box: &MediaBox,
+        _art_box: Option<(f64, f64, f64, f64)>,
+    ) -> Result<(), OutputError> {
+        self.pages.push(vec![]);
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn output_character(
+        &mut self,
+        trm: &Transform,
+        width: f64,
+        _spacing: f64,
+        font_size: f64,
+        char: &str,
+    ) -> Result<(), OutputError> {
+        // 글자 행렬의 크기 변환까지 반영한 실제 글자 크기
+        let size = font_size * (trm.m11 * trm.m22 - trm.m12 * trm.m21).abs().sqrt();
+        if let Some(page) = self.pages.last_mut() {
+            page.push(Glyph {
+                x: trm.m31,
+                y: trm.m32,
+                end: trm.m31 + width * size,
+                size,
+                text: char.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+}
+
+/// 기준선이 가까운 글자를 한 줄로 모아 위에서 아래로 늘어놓는다.
+/// 글자 사이가 벌어진 곳은 띄어 쓴다. 여러 단으로 짠 쪽은 단을 나누지 않는다.
+fn lines(glyphs: Vec<Glyph>) -> Vec<Line> {
+    let mut groups: Vec<Vec<Glyph>> = vec![];
+    for glyph in glyphs {
+        match groups
+            .iter_mut()
+            .find(|line| (line[0].y - glyph.y).abs() < line[0].size.max(glyph.size) * 0.5)
+        {
+            Some(line) => line.push(glyph),
+            None => groups.push(vec![glyph]),
+        }
+    }
+    groups.sort_by(|a, b| b[0].y.total_cmp(&a[0].y));
+    groups
+        .into_iter()
+        .filter_map(|mut glyphs| {
+            glyphs.sort_by(|a, b| a.x.total_cmp(&b.x));
+            let glyphs = glyphs
+                .into_iter()
+                .filter(|glyph| !glyph.text.trim().is_empty() || glyph.text == " ")
+                .collect::<Vec<_>>();
+            let mut text = String::new();
+            let mut end = f64::MIN;
+            for glyph in &glyphs {
+                let gap = !text.is_empty() && glyph.x > end + glyph.size * 0.15;
+                if (gap || glyph.text == " ") && !text.is_empty() && !text.ends_with(' ') {
+                    text.push(' ');
+                }
+                if glyph.text != " " {
+                    text.push_str(&glyph.text);
+                }
+                end = end.max(glyph.end);
+            }
+            let visible = glyphs.iter().filter(|glyph| glyph.text != " ");
+            let (x, size) = visible.fold((f64::MAX, 0f64), |(x, size), glyph| {
+                (x.min(glyph.x), size.max(glyph.size))
+            });
+            let text = text.trim().to_string();
+            (!text.is_empty()).then(|| Line {
+                x,
+                y: glyphs[0].y,
+                end,
+                size,
+                text,
+            })
+        })
+        .collect()
+}
+
+/// 쪽 번호만 적힌 줄("- 3 -", "12쪽", "3 / 10")이면 그 번호
+fn page_number(text: &str) -> Option<String> {
+    let number = text
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|part| !part.is_empty())?;
+    let rest = text
+        .chars()
+        .filter(|c| !c.is_ascii_digit() && !c.is_whitespace())
+        .collect::<String>();
+    matches!(
+        rest.as_str(),
+        "" | "-"
+            | "--"
+            | "–"
+            | "––"
+            | "—"
+            | "——"
+            | "()"
+            | "[]"
+            | "<>"
+            | "/"
+            | "쪽"
+            | "p."
+            | "페이지"
+    )
+    .then(|| number.to_string())
+}
+
+/// 숫자를 뺀 줄의 모양. 쪽 번호만 다른 머리글을 같은 줄로 본다.
+fn running_key(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_ascii_digit())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// 맨 위와 맨 아래 줄 가운데 머리글과 바닥글을 찾는다.
+/// 여러 쪽에 되풀이되는 줄이나 쪽 번호만 적힌 줄을 머리글과 바닥글로 본다.
+fn running_lines(pages: &[Vec<Line>]) -> Vec<Vec<usize>> {
+    let edges = |lines: &[Line]| -> Vec<usize> {
+        match lines.len() {
+            0 => vec![],
+            1 => vec![0],
+            len => vec![0, len - 1],
+        }
+    };
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for lines in pages {
+        for index in edges(lines) {
+            *counts.entry(running_key(&lines[index].text)).or_default() += 1;
+        }
+    }
+    let threshold = (pages.len() / 2).max(2);
+    pages
+        .iter()
+        .map(|lines| {
+            edges(lines)
+                .into_iter()
+                .filter(|index| {
+                    let text = &lines[*index].text;
+                    let key = running_key(text);
+                    page_number(text).is_some()
+                        || (!key.is_empty() && counts.get(&key).copied().unwrap_or(0) >= threshold)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 가운데 값
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    values.sort_by(f64::total_cmp);
+    values.get(values.len() / 2).copied()
+}
+
+/// 본문보다 큰 글자 크기를 큰 것부터 제목의 단계로 삼는다.
+fn heading_levels(pages: &[Vec<Line>], body: f64) -> Vec<f64> {
+    let mut sizes = pages
+        .iter()
+        .flatten()
+        .map(|line| (line.size * 2.0).round() / 2.0)
+        .filter(|size| *size > body * 1.15)
+        .collect::<Vec<_>>();
+    sizes.sort_by(|a, b| b.total_cmp(a));
+    sizes.dedup();
+    sizes.truncate(6);
+    sizes
+}
+
+/// 한 쪽의 줄을 문단과 제목으로 묶는다.
+/// 줄 사이가 벌어지거나, 첫 줄을 들여 쓰거나, 앞 줄이 짧게 끝나거나, 목록 머리로 시작하면 새 문단이다.
+fn page_blocks(lines: &[Line], body: f64, pitch: f64, headings: &[f64], blocks: &mut Vec<Block>) {
+    let level = |line: &Line| {
+        let size = (line.size * 2.0).round() / 2.0;
+        headings
+            .iter()
+            .position(|heading| *heading == size)
+            .map(|index| index as u8 + 1)
+    };
+    let body_lines = lines.iter().filter(|line| level(line).is_none());
+    let left = body_lines
+        .clone()
+        .map(|line| line.x)
+        .fold(f64::MAX, f64::min);
+    let right = body_lines.map(|line| line.end).fold(f64::MIN, f64::max);
+
+    let mut text = String::new();
+    let mut current: Option<u8> = None;
+    let mut previous: Option<&Line> = None;
+    let flush = |text: &mut String, current: Option<u8>, blocks: &mut Vec<Block>| {
+        if text.is_empty() {
+            return;
+        }
+        let runs = vec![Run::new(std::mem::take(text))];
+        blocks.push(match current {
+            Some(level) => Block::Heading { level, runs },
+            None => Block::Paragraph(runs),
+        });
+    };
+    for line in lines {
+        let line_level = level(line);
+        let new_block = match previous {
+            None => true,
+            Some(previous) => {
+                line_level != current
+                    || previous.y - line.y > pitch * 1.3
+                    || (line_level.is_none()
+                        && (line.x > left + body * 0.8
+                            || previous.end < right - body * 4.0
+                            || LIST_MARKER.is_match(&line.text)))
+            }
+        };
+        if new_block {
+            flush(&mut text, current, blocks);
+            current = line_level;
+        } else if text.ends_with('-')
+            && text
+                .chars()
+                .rev()
+                .nth(1)
+                .is_some_and(|c| c.is_ascii_alphabetic())
+            && line.text.starts_with(|c: char| c.is_ascii_lowercase())
+        {
+            // 줄 끝에서 나눈 영어 낱말을 다시 잇는다.
+            text.pop();
+        } else {
+            text.push(' ');
+        }
+        text.push_str(&line.text);
+        previous = Some(line);
+    }
+    flush(&mut text, current, blocks);
+}
+
+/// 글자 층이 있는 PDF를 읽는 순서대로 점자 책의 덩어리로 나눈다.
+/// 줄의 자리로 문단과 제목을 다시 묶고, 묵자의 쪽이 바뀌는 곳에 쪽 표시를 넣는다.
+/// 글자 없이 그림으로만 된(스캔한) PDF는 읽지 않는다.
+pub fn parse_pdf(bytes: &[u8], config: &PdfConfig) -> Result<Vec<Block>, BraillifyError> {
+    let mut document = Document::load_mem(bytes).map_err(error)?;
+    if document.is_encrypted() {
+        // 열기 암호가 없는 문서는 빈 암호로 풀린다.
+        document
+            .decrypt("")
+            .map_err(|_| error("encrypted PDF is not supported"))?;
+    }
+    let mut collector = Collector::default();
+    // pdf-extract는 지원하지 않는 글꼴이나 그래픽 상태를 만나면 panic하므로 오류로 바꾼다.
+    panic::catch_unwind(AssertUnwindSafe(|| output_doc(&document, &mut collector)))
+        .map_err(|_| error("unsupported PDF content"))?
+        .map_err(error)?;
+    let mut pages = collector.pages.into_iter().map(lines).collect::<Vec<_>>();
+    if pages.iter().all(Vec::is_empty) {
+        return Err(error(
+            "no text layer; scanned (image-only) PDF is not supported",
+        ));
+    }
+
+    // 쪽 번호 줄에서 묵자의 쪽 번호를 읽는다.
+    let running = running_lines(&pages);
+    let mut labels = vec![];
+    for (index, (lines, running)) in pages.iter_mut().zip(&running).enumerate() {
+        labels.push(
+            running
+                .iter()
+                .find_map(|line| page_number(&lines[*line].text))
+                .unwrap_or_else(|| (index + 1).to_string()),
+        );
+        if config.drop_headers_footers {
+            for line in running.iter().rev() {
+                lines.remove(*line);
+            }
+        }
+    }
+
+    let lines = pages.iter().flatten();
+    let body = median(lines.clone().map(|line| line.size).collect()).unwrap_or(10.0);
+    let pitch = median(
+        pages
+            .iter()
+            .flat_map(|lines| lines.windows(2).map(|pair| pair[0].y - pair[1].y))
+            .filter(|gap| *gap > 0.0)
+            .collect(),
+    )
+    .unwrap_or(body * 1.2);
+    let headings = heading_levels(&pages, body);
+
+    let mut blocks = vec![];
+    for (index, lines) in pages.iter().enumerate() {
+        // 첫 쪽도 묵자 쪽 번호를 적어 어느 쪽부터 옮겼는지 알린다.
+        blocks.push(Block::PrintPage(labels[index].clone()));
+        if lines.is_empty() {
+            blocks.push(Block::Paragraph(vec![Run {
+                note: true,
+                ..Run::new("글자 없는 쪽")
+            }]));
+            continue;
+        }
+        page_blocks(lines, body, pitch, &headings, &mut blocks);
+    }
+    Ok(blocks)
+}
+
+/// PDF를 점자 책으로 배치한다.
+pub fn encode_pdf(
+    bytes: &[u8],
+    pdf: &PdfConfig,
+    config: &BookConfig,
+) -> Result<Vec<Volume>, BraillifyError> {
+    format_book(&parse_pdf(bytes, pdf)?, config)
+}
+
+#[cfg(test)]
+mod test {
+    use pdf_extract::{
+        Object, Stream,
+        content::{Content, Operation},
+        dictionary,
+    };
+
+    use super::*;
+
+    /// (글자 크기, x, y, 글)을 쪽마다 적은 PDF를 만든다.
+    fn build_pdf(pages: &[&[(i64, i64, i64, &str)]]) -> Vec<u8> {
+        let mut document = Document::with_version("1.5");
+        let pages_id = document.new_object_id();
+        let font_id = document.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let resources_id = document.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+        let mut kids = vec![];
+        for lines in pages {
+            let mut operations = vec![];
+            for (size, x, y, text) in *lines {
+                operations.extend([
+                    Operation::new("BT", vec![]),
+                    Operation::new("Tf", vec!["F1".into(), (*size).into()]),
+                    Operation::new("Td", vec![(*x).into(), (*y).into()]),
+                    Operation::new("Tj", vec![Object::string_literal(*text)]),
+                    Operation::new("ET", vec![]),
+                ]);
+            }
+            let content = Content { operations }.encode().unwrap();
+            let content_id = document.add_object(Stream::new(dictionary! {}, content));
+            kids.push(Object::from(document.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            })));
+        }
+        document.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Count" => kids.len() as i64,
+                "Kids" => kids,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = document.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        document.trailer.set("Root", catalog_id);
+        let mut bytes = vec![];
+        document.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn sample() -> Vec<u8> {
+        build_pdf(&[
+            &[
+                (10, 72, 800, "Annual report"),
+                (20, 72, 750, "Notice"),
+                (12, 72, 720, "The office will close early on"),
+                (12, 72, 706, "Friday."),
+                (12, 90, 692, "Visit us next week."),
+                (12, 72, 678, "- 1 -"),
+            ],
+            &[
+                (10, 72, 800, "Annual report"),
+                (12, 72, 720, "1. Bring your ID."),
+                (12, 72, 706, "2. Bring a pen."),
+                (12, 72, 678, "- 2 -"),
+            ],
+        ])
+    }
+
+    #[test]
+    pub fn test_parse_pdf() {
+        let config = PdfConfig {
+            drop_headers_footers: true,
+        };
+        assert_eq!(
+            parse_pdf(&sample(), &config).unwrap(),
+            vec![
+                Block::PrintPage("1".to_string()),
+                Block::heading(1, "Notice"),
+                Block::paragraph("The office will close early on Friday."),
+                Block::paragraph("Visit us next week."),
+                Block::PrintPage("2".to_string()),
+                Block::paragraph("1. Bring your ID."),
+                Block::paragraph("2. Bring a pen."),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_parse_pdf_running_lines() {
+        let blocks = parse_pdf(&sample(), &PdfConfig::default()).unwrap();
+        assert_eq!(blocks[1], Block::paragraph("Annual report"));
+        assert!(blocks.contains(&Block::paragraph("- 2 -")));
+        assert_eq!(page_number("- 12 -").as_deref(), Some("12"));
+        assert_eq!(page_number("3 / 10").as_deref(), Some("3"));
+        assert_eq!(page_number("12쪽").as_deref(), Some("12"));
+        assert_eq!(page_number("제 3 장"), None);
+    }
+
+    #[test]
+    pub fn test_parse_pdf_error() {
+        assert!(matches!(
+            parse_pdf(b"not a pdf", &PdfConfig::default()),
+            Err(BraillifyError::DocumentError { .. })
+        ));
+        // 글자가 없는 쪽만 있으면 스캔한 문서로 본다.
+        assert!(matches!(
+            parse_pdf(&build_pdf(&[&[]]), &PdfConfig::default()),
+            Err(BraillifyError::DocumentError { error, .. }) if error.contains("scanned")
+        ));
+    }
+}
@@ -0,0 +1,394 @@
+#[cfg(any(feature = "docx", feature = "odt", feature = "hwp"))]
+use crate::book::Block;
+use crate::{
+    Encoder, EncodingConfig, computer, error::BraillifyError, latex, line::Encoded, mathml,
+    unicode::decode_unicode, utils,
+};
+
+/// 런의 언어를 문서 전체와 다르게 지정한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// 국어 문장 안의 글로 보고 로마자에 로마자표를 적는다.
+    Korean,
+    /// 외국어 글로 보고 로마자표를 적지 않는다.
+    English,
+}
+
+/// 같은 서식이 적용된 글의 한 부분
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Run {
+    pub text: String,
+    /// 드러냄표
+    pub emphasis: bool,
+    pub underline: bool,
+    pub bold: bool,
+    /// 기울인 글자와 취소선은 제56항에 없는 글자체이므로 점자에는 적지 않는다.
+    pub italic: bool,
+    pub strike: bool,
+    /// 컴퓨터 점자로 적을 코드
+    pub code: bool,
+    /// LaTeX 또는 MathML 수식
+    pub math: bool,
+    pub language: Option<Language>,
+    /// 국어 약자와 약어, 로마자 약자를 쓰지 않는다.
+    pub no_contraction: bool,
+    /// 점역자 주로 적을 글 (그림의 대체 텍스트 등)
+    pub note: bool,
+}
+
+impl Run {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 글을 빼고 서식이 모두 같은지 여부
+    pub(crate) fn same_format(&self, other: &Run) -> bool {
+        let plain = |run: &Run| Run {
+            text: String::new(),
+            ..run.clone()
+        };
+        plain(self) == plain(other)
+    }
+
+    /// 제56항 강조 글자체의 시작표와 종료표. 여러 서식이 겹치면 바깥부터 차례로 적는다.
+    fn indicators(&self) -> Vec<([u8; 2], [u8; 2])> {
+        let indicator = |start: [char; 2], end: [char; 2]| {
+            (
+                [decode_unicode(start[0]), decode_unicode(start[1])],
+                [decode_unicode(end[0]), decode_unicode(end[1])],
+            )
+        };
+        let mut indicators = vec![];
+        if self.emphasis || self.underline {
+            indicators.push(indicator(['⠠', '⠤'], ['⠤', '⠄']));
+        }
+        if self.bold {
+            indicators.push(indicator(['⠰', '⠤'], ['⠤', '⠆']));
+        }
+        indicators
+    }
+}
+
+/// 서식이 같은 글은 앞 런에 이어 적는다. 코드, 수식, 점역자 주는 한 덩어리씩 따로 둔다.
+pub(crate) fn push_run(runs: &mut Vec<Run>, run: Run) {
+    match runs.last_mut() {
+        Some(last) if !run.code && !run.math && !run.note && last.same_format(&run) => {
+            last.text.push_str(&run.text)
+        }
+        _ => runs.push(run),
+    }
+}
+
+/// LaTeX 또는 MathML 수식 런. 점자로 옮길 수 없는 수식은 `fallback`을 점역자 주로 적는다.
+pub(crate) fn math_run(math: &str, fallback: &str) -> Run {
+    let encoded = if math.trim_start().starts_with('<') {
+        mathml::encode_mathml(math).is_ok()
+    } else {
+        !math.trim().is_empty() && latex::encode_latex_math(math).is_ok()
+    };
+    if encoded {
+        Run {
+            math: true,
+            ..Run::new(math)
+        }
+    } else {
+        Run {
+            note: true,
+            ..Run::new(format!("수식 {}", fallback.trim()).trim_end())
+        }
+    }
+}
+
+/// 문단 앞뒤의 공백을 지우고 빈 런을 뺀다.
+pub(crate) fn trim_runs(mut runs: Vec<Run>) -> Vec<Run> {
+    if let Some(first) = runs.first_mut() {
+        first.text = first.text.trim_start().to_string();
+    }
+    if let Some(last) = runs.last_mut() {
+        last.text = last.text.trim_end().to_string();
+    }
+    runs.retain(|run| !run.text.is_empty());
+    runs
+}
+
+/// `style`과 같은 서식으로 글을 덧붙인다.
+#[cfg(any(feature = "docx", feature = "odt", feature = "hwp", feature = "hwpx"))]
+pub(crate) fn push_text(runs: &mut Vec<Run>, text: &str, style: &Run) {
+    push_run(
+        runs,
+        Run {
+            text: text.to_string(),
+            ..style.clone()
+        },
+    );
+}
+
+/// 각주 번호 뒤에 각주 내용을 적은 문단.
+/// 각주는 각주 번호가 나온 문단 바로 뒤에 적는다.
+#[cfg(any(feature = "docx", feature = "odt", feature = "hwp"))]
+pub(crate) fn note_paragraph(label: impl std::fmt::Display, runs: Vec<Run>) -> Block {
+    let mut note = vec![Run::new(format!("{label}) "))];
+    for run in trim_runs(runs) {
+        push_run(&mut note, run);
+    }
+    Block::Paragraph(note)
+}
+
+fn encode_content(
+    encoder: &mut Encoder,
+    run: &Run,
+    text: &str,
+    next_word: Option<&str>,
+    result: &mut Vec<u8>,
+) -> Result<(), BraillifyError> {
+    if run.note {
+        encoder.insert_note(text, result)?;
+    } else if run.code {
+        result.extend(computer::encode_computer_span(text)?);
+    } else if run.math {
+        if text.trim_start().starts_with('<') {
+            result.extend(mathml::encode_mathml(text)?);
+        } else {
+            result.extend(latex::encode_latex_math(text)?);
+        }
+    } else {
+        // 다음 단어와의 띄어쓰기까지 함께 적는다.
+        return encoder.encode_before(text, next_word, result);
+    }
+    if next_word.is_some() {
+        result.push(0);
+    }
+    Ok(())
+}
+
+/// 런 뒤에 띄어 쓰고 이어지는 다음 런의 첫 단어
+fn next_word<'a>(run: &Run, next_runs: &'a [Run]) -> Option<&'a str> {
+    if !run.text.ends_with(' ') || !run.indicators().is_empty() {
+        return None;
+    }
+    next_runs
+        .iter()
+        .map(|run| run.text.trim_start_matches(' '))
+        .find(|text| !text.is_empty())
+        .and_then(|text| text.split([' ', '\n']).next())
+        .filter(|word| !word.is_empty())
+}
+
+fn encode_run(
+    encoder: &mut Encoder,
+    run: &Run,
+    next_word: Option<&str>,
+    result: &mut Vec<u8>,
+) -> Result<(), BraillifyError> {
+    let english_indicator = encoder.english_indicator;
+    if let Some(language) = run.language {
+        encoder.english_indicator = language == Language::Korean;
+    }
+    encoder.contractions = !run.no_contraction;
+    let indicators = run.indicators();
+    // 강조는 문단마다 따로 묶는다.
+    let line_count = run.text.split('\n').count();
+    for (idx, line) in run.text.split('\n').enumerate() {
+        let next_word = next_word.filter(|_| idx + 1 == line_count);
+        if idx > 0 {
+            result.push(255);
+        }
+        let text = line.trim_matches(' ');
+        if line.starts_with(' ') && result.last().is_some_and(|c| *c != 0 && *c != 255) {
+            result.push(0);
+        }
+        if !text.is_empty() {
+            for (start, _) in &indicators {
+                result.extend(start);
+            }
+            encode_content(encoder, run, text, next_word, result)?;
+            if !indicators.is_empty() {
+                // 종료표 앞에서 로마자 구간을 닫는다.
+                encoder.close_foreign(result);
+            }
+            for (_, end) in indicators.iter().rev() {
+                result.extend(end);
+            }
+        }
+        if !text.is_empty() && line.ends_with(' ') && next_word.is_none() {
+            result.push(0);
+        }
+    }
+    encoder.english_indicator = english_indicator;
+    encoder.contractions = true;
+    Ok(())
+}
+
+/// 서식이 있는 글을 점자로 바꾼다.
+/// 강조된 런은 낱말이든 여러 낱말이든 앞뒤에 제56항의 시작표와 종료표를 적는다.
+pub fn encode_rich(runs: &[Run]) -> Result<Vec<u8>, BraillifyError> {
+    encode_rich_with_breaks(runs, &EncodingConfig::default()).map(|encoded| encoded.cells)
+}
+
+/// 서식이 있는 글을 점자로 바꾸고, 단어 안에서 줄을 바꿀 수 있는 자리를 함께 돌려준다.
+pub fn encode_rich_with_breaks(
+    runs: &[Run],
+    config: &EncodingConfig,
+) -> Result<Encoded, BraillifyError> {
+    let has_korean = runs
+        .iter()
+        .any(|run| run.text.chars().any(utils::is_korean_char));
+    let mut encoder = Encoder::new(config.english_indicator && has_korean);
+    encoder.whitespace = config.whitespace;
+    encoder.tab_width = config.tab_width;
+    encoder.chemistry = config.chemistry;
+    let mut result = vec![];
+    for (idx, run) in runs.iter().enumerate() {
+        let next_word = next_word(run, &runs[idx + 1..]);
+        encode_run(&mut encoder, run, next_word, &mut result)?;
+    }
+    encoder.finish(&mut result)?;
+    Ok(Encoded {
+        cells: result,
+        breaks: encoder.breaks,
+        joins: encoder.joins,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{encode, unicode::encode_unicode};
+
+    use super::*;
+
+    fn to_unicode(runs: &[Run]) -> String {
+        encode_rich(runs)
+            .unwrap()
+            .iter()
+            .map(|c| encode_unicode(*c))
+            .collect()
+    }
+
+    #[test]
+    pub fn test_encode_rich() {
+        assert_eq!(
+            to_unicode(&[
+                Run::new("서울은 대한민국의 "),
+                Run {
+                    bold: true,
+                    ..Run::new("수도")
+                },
+                Run::new("이다."),
+            ]),
+            "⠠⠎⠯⠵⠀⠊⠗⠚⠒⠑⠟⠈⠍⠁⠺⠀⠰⠤⠠⠍⠊⠥⠤⠆⠕⠊⠲"
+        );
+        assert_eq!(
+            to_unicode(&[
+                Run {
+                    emphasis: true,
+                    ..Run::new("배부른 돼지")
+                },
+                Run::new("보다는 배고픈 "),
+                Run {
+                    emphasis: true,
+                    ..Run::new("소크라테스")
+                },
+                Run::new("가 되겠다."),
+            ]),
+            "⠠⠤⠘⠗⠘⠍⠐⠵⠀⠊⠧⠗⠨⠕⠤⠄⠘⠥⠊⠉⠵⠀⠘⠗⠈⠥⠙⠵⠀⠠⠤⠠⠥⠋⠪⠐⠣⠓⠝⠠⠪⠤⠄⠫⠀⠊⠽⠈⠝⠌⠊⠲"
+        );
+        assert_eq!(
+            to_unicode(&[
+                Run::new("이것은 "),
+                Run {
+                    underline: true,
+                    bold: true,
+                    ..Run::new("apple")
+                },
+                Run::new("이다."),
+            ]),
+            "⠕⠸⠎⠵⠀⠠⠤⠰⠤⠴⠁⠏⠏⠇⠑⠲⠤⠆⠤⠄⠕⠊⠲"
+        );
+    }
+
+    #[test]
+    pub fn test_encode_rich_plain() {
+        for runs in [
+            vec!["BMI", "(지수)"],
+            vec!["Roma ", "[ㄹㄹ로마]"],
+            vec!["체질량 ", "지수(BMI)"],
+            vec!["안녕\n", "반가워"],
+            vec!["사과는 ", "apple ", "이다."],
+            vec!["a ", "b ", "c"],
+        ] {
+            let text = runs.concat();
+            let runs = runs.into_iter().map(Run::new).collect::<Vec<_>>();
+            assert_eq!(
+                encode_rich(&runs).unwrap(),
+                encode(&text).unwrap(),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_encode_rich_attributes() {
+        assert_eq!(
+            to_unicode(&[
+                Run::new("명령 "),
+                Run {
+                    code: true,
+                    ..Run::new("ls -al")
+                },
+            ]),
+            "⠑⠻⠐⠻⠀⠠⠶⠇⠎⠀⠤⠁⠇⠶⠄"
+        );
+        assert_eq!(
+            to_unicode(&[
+                Run::new("답은 "),
+                Run {
+                    math: true,
+                    ..Run::new("x^2")
+                },
+            ]),
+            "⠊⠃⠵⠀⠭⠘⠼⠃"
+        );
+        assert_eq!(
+            to_unicode(&[Run {
+                no_contraction: true,
+                ..Run::new("first")
+            }]),
+            "⠋⠊⠗⠎⠞"
+        );
+        assert_eq!(to_unicode(&[Run::new("first")]), "⠋⠊⠗⠌");
+        assert_eq!(
+            to_unicode(&[Run {
+                no_contraction: true,
+                ..Run::new("그래서 가는 것")
+            }]),
+            "⠈⠪⠐⠗⠠⠎⠀⠈⠣⠉⠪⠒⠀⠈⠎⠄"
+        );
+        assert_eq!(to_unicode(&[Run::new("그래서 가는 것")]), "⠁⠎⠀⠫⠉⠵⠀⠸⠎");
+        assert_eq!(
+            to_unicode(&[
+                Run {
+                    italic: true,
+                    ..Run::new("사과")
+                },
+                Run {
+                    strike: true,
+                    ..Run::new("는")
+                },
+            ]),
+            to_unicode(&[Run::new("사과는")])
+        );
+        assert_eq!(
+            to_unicode(&[
+                Run::new("사과는 "),
+                Run {
+                    language: Some(Language::English),
+                    ..Run::new("apple")
+                },
+            ]),
+            "⠇⠈⠧⠉⠵⠀⠁⠏⠏⠇⠑"
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{
+    EncodingConfig, encode_with_breaks,
+    error::BraillifyError,
+    line::{Encoded, Indent, break_lines_indented},
+    rich::{Run, encode_rich_with_breaks},
+    unicode::decode_unicode,
+};
+
+/// 표의 칸과 다음 칸을 잇는 안내점
+const GUIDE_DOT: u8 = decode_unicode('⠐');
+/// 제목 행과 내용 행을 나누는 선
+const HEADER_RULE: u8 = decode_unicode('⠒');
+
+/// 표의 한 칸. 여러 행이나 열을 합친 칸은 `row_span`, `col_span`에 그 수를 적는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableCell {
+    pub text: String,
+    /// 점역자 주나 수식이 섞인 칸의 글. 비어 있지 않으면 `text` 대신 이것을 적는다.
+    pub runs: Vec<Run>,
+    pub row_span: usize,
+    pub col_span: usize,
+}
+
+impl TableCell {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            runs: vec![],
+            row_span: 1,
+            col_span: 1,
+        }
+    }
+}
+
+/// 표. 앞의 `header_rows`개 행은 제목 행이다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    pub rows: Vec<Vec<TableCell>>,
+    pub header_rows: usize,
+}
+
+impl Table {
+    /// 문자열의 행으로 표를 만든다.
+    pub fn from_rows<R, S>(rows: impl IntoIterator<Item = R>, header_rows: usize) -> Self
+    where
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(TableCell::new).collect())
+                .collect(),
+            header_rows,
+        }
+    }
+
+    /// 합친 칸을 풀어 행과 열마다 그 자리를 차지한 칸을 찾는다.
+    fn grid(&self) -> Vec<Vec<Option<&TableCell>>> {
+        let mut grid: Vec<Vec<Option<(usize, usize)>>> = vec![vec![]; self.rows.len()];
+        for (r, row) in self.rows.iter().enumerate() {
+            let mut c = 0;
+            for (idx, cell) in row.iter().enumerate() {
+                while grid[r].get(c).is_some_and(Option::is_some) {
+                    c += 1;
+                }
+                for covered in grid.iter_mut().skip(r).take(cell.row_span.max(1)) {
+                    let end = c + cell.col_span.max(1);
+                    if covered.len() < end {
+                        covered.resize(end, None);
+                    }
+                    for slot in &mut covered[c..end] {
+                        slot.get_or_insert((r, idx));
+                    }
+                }
+                c += cell.col_span.max(1);
+            }
+        }
+        grid.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|slot| slot.map(|(r, idx)| &self.rows[r][idx]))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// 한 행에서 칸이 시작되는 열과 차지하는 열 수
+fn row_cells<'a>(row: &[Option<&'a TableCell>]) -> Vec<(usize, usize, &'a TableCell)> {
+    let mut cells: Vec<(usize, usize, &TableCell)> = vec![];
+    for (c, slot) in row.iter().enumerate() {
+        let Some(cell) = slot else {
+            continue;
+        };
+        match cells.last_mut() {
+            Some((_, span, last)) if std::ptr::eq(*last, *cell) => *span += 1,
+            _ => cells.push((c, 1, cell)),
+        }
+    }
+    cells
+}
+
+/// 칸 안의 글을 한 줄로 적는다. `heading`이 있으면 열 제목과 쌍점을 앞에 적는다.
+fn encode_cell(
+    cell: &TableCell,
+    heading: Option<&str>,
+    config: &EncodingConfig,
+) -> Result<Encoded, BraillifyError> {
+    let heading = heading.map(|heading| format!("{heading}: "));
+    if cell.runs.is_empty() {
+        let text = cell.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        return encode_with_breaks(&(heading.unwrap_or_default() + &text), config.clone());
+    }
+    let mut runs = heading.map(Run::new).into_iter().collect::<Vec<_>>();
+    runs.extend(cell.runs.iter().cloned());
+    encode_rich_with_breaks(&runs, config)
+}
+
+/// 열마다 칸을 나란히 적는다. 칸 내용 뒤는 한 칸 띄우고 다음 열 앞까지 안내점으로 잇는다.
+/// 표가 줄 너비에 들어가지 않으면 `None`을 돌려준다.
+fn columnar(
+    table: &Table,
+    grid: &[Vec<Option<&TableCell>>],
+    width: usize,
+    config: &EncodingConfig,
+) -> Result<Option<Vec<Vec<u8>>>, BraillifyError> {
+    let columns = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let mut rows = vec![];
+    for (r, row) in grid.iter().enumerate() {
+        let mut cells = vec![];
+        for (column, span, cell) in row_cells(row) {
+            // 여러 행을 합친 칸은 첫 행에만 적는다.
+            let first_row = r == 0
+                || !grid[r - 1]
+                    .get(column)
+                    .is_some_and(|above| above.is_some_and(|above| std::ptr::eq(above, cell)));
+            let encoded = if first_row {
+                encode_cell(cell, None, config)?.cells
+            } else {
+                vec![]
+            };
+            cells.push((column, span, encoded));
+        }
+        rows.push(cells);
+    }
+
+    // 열 너비는 그 열에 놓인 칸 가운데 가장 긴 것에 맞춘다. 합친 칸은 마지막 열을 넓힌다.
+    let mut widths = vec![0; columns];
+    for cells in &rows {
+        for (column, _, encoded) in cells.iter().filter(|(_, span, _)| *span == 1) {
+            widths[*column] = widths[*column].max(encoded.len());
+        }
+    }
+    for cells in &rows {
+        for (column, span, encoded) in cells.iter().filter(|(_, span, _)| *span > 1) {
+            let spanned = widths[*column..column + span].iter().sum::<usize>() + span - 1;
+            if spanned < encoded.len() {
+                widths[column + span - 1] += encoded.len() - spanned;
+            }
+        }
+    }
+    // 마지막 열이 아니면 안내점 앞뒤의 빈칸과 안내점 두 칸을 둔다.
+    for (column, width) in widths.iter_mut().enumerate() {
+        if column + 1 < columns {
+            *width += 3;
+        }
+    }
+    let total = widths.iter().sum::<usize>() + columns.saturating_sub(1);
+    if total > width {
+        return Ok(None);
+    }
+
+    let mut lines = vec![];
+    for (r, cells) in rows.iter().enumerate() {
+        if r == table.header_rows && r > 0 {
+            lines.push(vec![HEADER_RULE; total]);
+        }
+        let mut line = vec![];
+        for (column, span, encoded) in cells {
+            line.resize(widths[..*column].iter().sum::<usize>() + column, 0);
+            line.extend(encoded);
+            if column + span >= columns {
+                continue;
+            }
+            let end = widths[..column + span].iter().sum::<usize>() + column + span - 1;
+            let fill = end.saturating_sub(line.len());
+            if r >= table.header_rows && !encoded.is_empty() && fill >= 2 {
+                line.push(0);
+                line.extend(std::iter::repeat_n(GUIDE_DOT, fill - 1));
+            }
+        }
+        while line.last() == Some(&0) {
+            line.pop();
+        }
+        lines.push(line);
+    }
+    Ok(Some(lines))
+}
+
+/// 행마다 첫 칸을 적고, 나머지 칸은 두 칸 들여 열 제목과 함께 한 줄씩 적는다.
+fn linear(
+    table: &Table,
+    grid: &[Vec<Option<&TableCell>>],
+    width: usize,
+    config: &EncodingConfig,
+) -> Result<Vec<Vec<u8>>, BraillifyError> {
+    let headings = grid
+        .get(..table.header_rows)
+        .and_then(|rows| rows.last())
+        .map(|row| {
+            row.iter()
+                .map(|slot| slot.map(|cell| cell.text.trim()).unwrap_or_default())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let mut lines = vec![];
+    for row in grid.iter().skip(table.header_rows) {
+        for (idx, (column, _, cell)) in row_cells(row).into_iter().enumerate() {
+            let heading = headings
+                .get(column)
+                .filter(|heading| idx > 0 && !heading.is_empty());
+            let indent = if idx == 0 { 0 } else { 2 };
+            lines.extend(break_lines_indented(
+                &encode_cell(cell, heading.copied(), config)?,
+                width,
+                Indent {
+                    first: indent,
+                    runover: indent + 2,
+                },
+            ));
+        }
+    }
+    Ok(lines)
+}
+
+/// 표를 점자로 배치한다. 줄 너비에 들어가면 열을 나란히 놓고 안내점으로 잇고,
+/// 들어가지 않으면 행마다 칸을 한 줄씩 쌓아 열 제목을 되풀이해 적는다.
+pub fn format_table(
+    table: &Table,
+    width: usize,
+    config: &EncodingConfig,
+) -> Result<Vec<Vec<u8>>, BraillifyError> {
+    let grid = table.grid();
+    if let Some(lines) = columnar(table, &grid, width, config)? {
+        return Ok(lines);
+    }
+    linear(table, &grid, width, config)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::unicode::encode_unicode;
+
+    use super::*;
+
+    fn to_unicode(table: &Table, width: usize) -> Vec<String> {
+        format_table(table, width, &EncodingConfig::default())
+            .unwrap()
+            .iter()
+            .map(|line| line.iter().map(|c| encode_unicode(*c)).collect())
+            .collect()
+    }
+
+    #[test]
+    pub fn test_grid() {
+        let mut table = Table::from_rows([vec!["가", "나", "다"], vec!["라", "마"]], 0);
+        table.rows[0][0].row_span = 2;
+        let grid = table.grid();
+        let text = |r: usize, c: usize| grid[r][c].map(|cell| cell.text.as_str());
+        assert_eq!(text(1, 0), Some("가"));
+        assert_eq!(text(1, 1), Some("라"));
+        assert_eq!(text(1, 2), Some("마"));
+        table.rows[0][1].col_span = 2;
+        let grid = table.grid();
+        assert_eq!(grid[0].len(), 4);
+        assert_eq!(row_cells(&grid[0]).len(), 3);
+    }
+
+    #[test]
+    pub fn test_format_table() {
+        let table = Table::from_rows(
+            [
+                vec!["과일", "값"],
+                vec!["사과", "1000"],
+                vec!["포도", "500"],
+            ],
+            1,
+        );
+        assert_eq!(
+            to_unicode(&table, 32),
+            vec![
+                "⠈⠧⠕⠂⠀⠀⠀⠀⠫⠃⠄",
+                "⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒",
+                "⠇⠈⠧⠀⠐⠐⠐⠀⠼⠁⠚⠚⠚",
+                "⠙⠥⠊⠥⠀⠐⠐⠀⠼⠑⠚⠚"
+            ]
+        );
+        assert_eq!(
+            to_unicode(&table, 10),
+            vec!["⠇⠈⠧", "⠀⠀⠫⠃⠄⠐⠂", "⠀⠀⠀⠀⠼⠁⠚⠚⠚", "⠙⠥⠊⠥", "⠀⠀⠫⠃⠄⠐⠂", "⠀⠀⠀⠀⠼⠑⠚⠚"]
+        );
+    }
+
+    #[test]
+    pub fn test_format_table_runs() {
+        let mut table = Table::from_rows([vec!["과일", "값"], vec!["사과", "1000"]], 1);
+        // 칸 안의 그림 대체 텍스트는 점역자 주로, 수식은 수식으로 적는다.
+        table.rows[1][0].runs = vec![
+            Run::new("사과 "),
+            Run {
+                note: true,
+                ..Run::new("빨강")
+            },
+        ];
+        table.rows[1][1].runs = vec![Run {
+            math: true,
+            ..Run::new("<math><msup><mi>x</mi><mn>2</mn></msup></math>")
+        }];
+        assert_eq!(
+            to_unicode(&table, 32),
+            vec![
+                "⠈⠧⠕⠂⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠫⠃⠄",
+                "⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒",
+                "⠇⠈⠧⠀⠸⠦⠠⠘⠂⠫⠶⠴⠇⠀⠐⠐⠀⠭⠘⠼⠃"
+            ]
+        );
+        assert_eq!(
+            to_unicode(&table, 12),
+            vec!["⠇⠈⠧", "⠀⠀⠸⠦⠠⠘⠂⠫⠶⠴⠇", "⠀⠀⠫⠃⠄⠐⠂⠀⠭⠘⠼⠃"]
+        );
+    }
+
+    #[test]
+    pub fn test_format_table_merged() {
+        let mut table = Table::from_rows(
+            [vec!["구분", "값"], vec!["가", "1", "2"], vec!["3", "4"]],
+            1,
+        );
+        table.rows[0][1].col_span = 2;
+        table.rows[1][0].row_span = 2;
+        assert_eq!(
+            to_unicode(&table, 32),
+            vec![
+                "⠈⠍⠘⠛⠀⠀⠀⠀⠫⠃⠄",
+                "⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒",
+                "⠫⠀⠐⠐⠐⠐⠐⠀⠼⠁⠀⠐⠐⠀⠼⠃",
+                "⠀⠀⠀⠀⠀⠀⠀⠀⠼⠉⠀⠐⠐⠀⠼⠙"
+            ]
+        );
+    }
+}
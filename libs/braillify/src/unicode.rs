@@ -1,3 +1,5 @@
+use crate::error::BraillifyError;
+
 pub fn encode_unicode(text: u8) -> char {
     if text == 255 {
         return '\n';
@@ -12,6 +14,28 @@ pub const fn decode_unicode(text: char) -> u8 {
     (text as u32 - 0x2800) as u8
 }
 
+/// 북미 점자 ASCII(BRF 파일)에서 점형 0~63에 대응하는 글자.
+/// 6점 컴퓨터 점자도 이 표를 따른다.
+pub(crate) const BRAILLE_ASCII: &str =
+    " A1B'K2L@CIF/MSP\"E3H9O6R^DJG>NTQ,*5<-U8V.%[$+X!&;:4\\0Z7(_?W]#Y)=";
+
+/// 점형을 점자 ASCII로 적는다. 점자 ASCII는 6점 점자만 나타내므로
+/// 7점이나 8점을 찍은 칸은 `InvalidCharacter` 오류를 낸다.
+pub fn encode_braille_ascii(text: u8) -> Result<char, BraillifyError> {
+    if text == 255 {
+        return Ok('\n');
+    }
+    BRAILLE_ASCII
+        .as_bytes()
+        .get(text as usize)
+        .map(|c| *c as char)
+        .ok_or_else(|| BraillifyError::InvalidCharacter {
+            character: encode_unicode(text),
+            position: None,
+            context: "Braille ASCII cannot represent dots 7 and 8".to_string(),
+        })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -83,4 +107,15 @@ mod test {
         assert_eq!(encode_unicode(63), '⠿');
         assert_eq!(encode_unicode(255), '\n');
     }
+
+    #[test]
+    pub fn test_encode_braille_ascii() {
+        assert_eq!(encode_braille_ascii(0), Ok(' '));
+        assert_eq!(encode_braille_ascii(decode_unicode('⠁')), Ok('A'));
+        assert_eq!(encode_braille_ascii(decode_unicode('⠼')), Ok('#'));
+        assert_eq!(encode_braille_ascii(decode_unicode('⠿')), Ok('='));
+        assert_eq!(encode_braille_ascii(255), Ok('\n'));
+        assert!(encode_braille_ascii(decode_unicode('⡁')).is_err());
+        assert!(encode_braille_ascii(decode_unicode('⢀')).is_err());
+    }
 }
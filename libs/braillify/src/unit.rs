@@ -0,0 +1,156 @@
+use phf::{phf_map, phf_set};
+
+use crate::{english, error::BraillifyError, unicode::decode_unicode};
+
+/// 숫자 앞뒤에 붙여 쓰는 단위 기호와 화폐 기호
+static SIGN_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    '%' => &[decode_unicode('⠨'), decode_unicode('⠴')],
+    '‰' => &[decode_unicode('⠨'), decode_unicode('⠴'), decode_unicode('⠴')],
+    '℃' => &[decode_unicode('⠘'), decode_unicode('⠚'), decode_unicode('⠠'), decode_unicode('⠉')],
+    '℉' => &[decode_unicode('⠘'), decode_unicode('⠚'), decode_unicode('⠠'), decode_unicode('⠋')],
+    '$' => &[decode_unicode('⠈'), decode_unicode('⠎')],
+    '₩' => &[decode_unicode('⠈'), decode_unicode('⠺')],
+    '€' => &[decode_unicode('⠈'), decode_unicode('⠑')],
+    '£' => &[decode_unicode('⠈'), decode_unicode('⠇')],
+    '¥' => &[decode_unicode('⠈'), decode_unicode('⠽')],
+};
+
+/// 한 글자로 된 호환용 단위 문자는 로마자 단위로 풀어 적는다.
+static COMPATIBILITY_MAP: phf::Map<char, &'static str> = phf_map! {
+    '㎎' => "mg",
+    '㎏' => "kg",
+    '㎜' => "mm",
+    '㎝' => "cm",
+    '㎞' => "km",
+    '㎠' => "cm²",
+    '㎡' => "m²",
+    '㎢' => "km²",
+    '㎤' => "cm³",
+    '㎥' => "m³",
+    '㎖' => "mL",
+    '㎗' => "dL",
+    '㎘' => "kL",
+    'ℓ' => "L",
+    '㏄' => "cc",
+    '㎈' => "cal",
+    '㎉' => "kcal",
+    '㎐' => "Hz",
+    '㎑' => "kHz",
+    '㎒' => "MHz",
+    '㎓' => "GHz",
+    '㎾' => "kW",
+    '㎽' => "mW",
+    '㎸' => "kV",
+    '㎳' => "ms",
+    '㏊' => "ha",
+    '㏈' => "dB",
+    '㎅' => "KB",
+    '㎆' => "MB",
+    '㎇' => "GB",
+};
+
+/// 숫자 뒤에 붙어 나올 때 단위로 읽는 로마자 약어
+static UNITS: phf::Set<&'static str> = phf_set! {
+    "mm", "cm", "m", "km", "mg", "g", "kg", "t", "ml", "mL", "dl", "dL", "l", "L", "kL",
+    "cc", "ms", "s", "min", "h", "Hz", "kHz", "MHz", "GHz", "W", "kW", "kWh", "mW", "V",
+    "kV", "mA", "cal", "kcal", "ha", "ppm", "dB", "KB", "MB", "GB", "TB", "px", "pt",
+};
+
+pub fn is_unit_sign(c: char) -> bool {
+    SIGN_MAP.contains_key(&c)
+}
+
+pub fn encode_unit_sign(c: char) -> Result<&'static [u8], BraillifyError> {
+    SIGN_MAP
+        .get(&c)
+        .copied()
+        .ok_or(BraillifyError::InvalidSymbolCharacter {
+            character: c,
+            position: None,
+        })
+}
+
+/// 호환용 단위 문자와 `°C`, `°F`를 풀어 쓴 단어를 돌려준다. 바꿀 것이 없으면 None
+pub fn expand_compatibility_units(word: &str) -> Option<String> {
+    if !word.chars().any(|c| COMPATIBILITY_MAP.contains_key(&c)) && !word.contains('°') {
+        return None;
+    }
+    let mut result = String::with_capacity(word.len());
+    for c in word.chars() {
+        match COMPATIBILITY_MAP.get(&c) {
+            Some(unit) => result.push_str(unit),
+            None => result.push(c),
+        }
+    }
+    let result = result.replace("°C", "℃").replace("°F", "℉");
+    (result != word).then_some(result)
+}
+
+/// 숫자 바로 뒤에 이어지는 로마자 단위를 적는다.
+/// 단위 앞에는 로마자표를 적지 않고, a~j로 시작하면 숫자와 구별하기 위해 ⠰을 적는다.
+/// 적은 점자와 읽은 글자 수를 돌려준다.
+pub fn encode_unit_after_number(chars: &[char]) -> Option<(Vec<u8>, usize)> {
+    let len = chars
+        .iter()
+        .position(|c| !c.is_ascii_alphabetic())
+        .unwrap_or(chars.len());
+    let unit = chars[..len].iter().collect::<String>();
+    if !UNITS.contains(unit.as_str()) {
+        return None;
+    }
+    let mut result = vec![];
+    if ('a'..='j').contains(&chars[0]) {
+        result.push(decode_unicode('⠰'));
+    }
+    for c in &chars[..len] {
+        if c.is_uppercase() {
+            result.push(decode_unicode('⠠'));
+        }
+        result.extend(english::encode_english_letter(*c).ok()?);
+    }
+    Some((result, len))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_expand_compatibility_units() {
+        assert_eq!(expand_compatibility_units("5㎏"), Some("5kg".to_string()));
+        assert_eq!(
+            expand_compatibility_units("3㎡이다"),
+            Some("3m²이다".to_string())
+        );
+        assert_eq!(expand_compatibility_units("30°C"), Some("30℃".to_string()));
+        assert_eq!(expand_compatibility_units("30°"), None);
+        assert_eq!(expand_compatibility_units("가나"), None);
+    }
+
+    #[test]
+    pub fn test_encode_unit_after_number() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(
+            encode_unit_after_number(&chars("kg이다")).unwrap(),
+            (vec![5, 27], 2)
+        );
+        assert_eq!(
+            encode_unit_after_number(&chars("cm")).unwrap(),
+            (vec![48, 9, 13], 2)
+        );
+        assert_eq!(
+            encode_unit_after_number(&chars("mL")).unwrap(),
+            (vec![13, 32, 7], 2)
+        );
+        assert!(encode_unit_after_number(&chars("kgs")).is_none());
+        assert!(encode_unit_after_number(&chars("st")).is_none());
+    }
+
+    #[test]
+    pub fn test_encode_unit_sign() {
+        assert!(is_unit_sign('%'));
+        assert!(!is_unit_sign('a'));
+        assert_eq!(encode_unit_sign('%').unwrap(), &[40, 52]);
+        assert!(encode_unit_sign('a').is_err());
+    }
+}
@@ -0,0 +1,498 @@
+use crate::error::BraillifyError;
+
+/// XML 노드
+#[derive(Debug, Clone, PartialEq)]
+pub enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+/// XML 요소
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+    /// 이름공간 접두어(`m:math`의 `m:`)를 뗀 요소 이름
+    pub fn local_name(&self) -> &str {
+        local_name(&self.name)
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name || local_name(key) == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter().filter_map(|child| match child {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    /// 요소를 다시 XML 문자열로 적는다.
+    pub fn to_xml(&self) -> String {
+        let escape = |s: &str| {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+        let mut xml = format!("<{}", self.name);
+        for (key, value) in &self.attributes {
+            xml.push_str(&format!(" {}=\"{}\"", key, escape(value)));
+        }
+        xml.push('>');
+        for child in &self.children {
+            match child {
+                XmlNode::Element(element) => xml.push_str(&element.to_xml()),
+                XmlNode::Text(text) => xml.push_str(&escape(text)),
+            }
+        }
+        xml.push_str(&format!("</{}>", self.name));
+        xml
+    }
+
+    /// 하위 요소를 포함한 모든 텍스트를 이어 붙인다.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        collect_text(&self.children, &mut text);
+        text
+    }
+}
+
+fn collect_text(nodes: &[XmlNode], text: &mut String) {
+    for node in nodes {
+        match node {
+            XmlNode::Element(element) => collect_text(&element.children, text),
+            XmlNode::Text(t) => text.push_str(t),
+        }
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+fn named_entity(name: &str) -> Option<char> {
+    Some(match name {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "ndash" => '–',
+        "mdash" => '—',
+        "copy" => '©',
+        "minus" => '−',
+        "times" => '×',
+        "divide" | "div" => '÷',
+        "PlusMinus" | "pm" | "plusmn" => '±',
+        "le" | "leq" => '≤',
+        "ge" | "geq" => '≥',
+        "ne" | "NotEqual" => '≠',
+        "middot" | "centerdot" | "sdot" => '·',
+        "pi" => 'π',
+        "InvisibleTimes" | "it" => '\u{2062}',
+        "ApplyFunction" | "af" => '\u{2061}',
+        "InvisibleComma" | "ic" => '\u{2063}',
+        _ => return None,
+    })
+}
+
+/// `&lt;`, `&#44032;`, `&#xAC00;` 같은 문자 참조를 풀어 쓴다.
+pub fn decode_entities(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let name = &rest[1..end];
+            let c = if let Some(hex) = name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else if let Some(dec) = name.strip_prefix('#') {
+                dec.parse().ok().and_then(char::from_u32)
+            } else {
+                named_entity(name)
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 내용이 없는 HTML 요소
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// 내용을 마크업으로 읽지 않는 HTML 요소
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// 열린 `<p>`를 닫는 블록 요소
+const BLOCK_ELEMENTS: [&str; 26] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "div",
+    "dl",
+    "fieldset",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+/// HTML에서 닫는 태그를 생략할 수 있는 요소가 새 요소 앞에서 닫히는지 여부
+fn implies_end(open: &str, next: &str) -> bool {
+    match open {
+        "p" => BLOCK_ELEMENTS.contains(&next),
+        "li" => next == "li",
+        "dt" | "dd" => matches!(next, "dt" | "dd"),
+        "td" | "th" => matches!(next, "td" | "th" | "tr"),
+        "tr" => next == "tr",
+        "thead" | "tbody" | "tfoot" => matches!(next, "thead" | "tbody" | "tfoot"),
+        "option" => next == "option",
+        _ => false,
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// HTML처럼 닫는 태그를 생략하거나 따옴표 없는 속성 값을 쓴 문서를 받아들인다.
+    html: bool,
+    /// 열려 있는 요소의 이름
+    open: Vec<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, error: impl Into<String>) -> BraillifyError {
+        BraillifyError::XmlParseError {
+            position: self.pos,
+            error: error.into(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_past(&mut self, end: &str) -> Result<(), BraillifyError> {
+        match self.rest().find(end) {
+            Some(idx) => {
+                self.pos += idx + end.len();
+                Ok(())
+            }
+            None => Err(self.error(format!("missing '{}'", end))),
+        }
+    }
+
+    fn read_name(&mut self) -> Result<&'a str, BraillifyError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '='))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// `close`가 Some이면 해당 닫는 태그가 나올 때까지, None이면 입력 끝까지 읽는다.
+    fn parse_nodes(&mut self, close: Option<&str>) -> Result<Vec<XmlNode>, BraillifyError> {
+        let mut nodes = vec![];
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return match close {
+                    Some(name) if !self.html => Err(self.error(format!("missing '</{}>'", name))),
+                    _ => Ok(nodes),
+                };
+            }
+            if !rest.starts_with('<') {
+                let len = rest.find('<').unwrap_or(rest.len());
+                nodes.push(XmlNode::Text(decode_entities(&rest[..len])));
+                self.pos += len;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let Some(end) = cdata.find("]]>") else {
+                    return Err(self.error("missing ']]>'"));
+                };
+                nodes.push(XmlNode::Text(cdata[..end].to_string()));
+                self.pos += "<![CDATA[".len() + end + "]]>".len();
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else if rest.starts_with("</") {
+                let start = self.pos;
+                self.pos += 2;
+                let name = self.read_name()?;
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.error("expected '>'"));
+                }
+                self.pos += 1;
+                match close {
+                    Some(expected) if self.same_name(expected, name) => return Ok(nodes),
+                    _ if !self.html => {
+                        return Err(self.error(format!("unexpected '</{}>'", name)));
+                    }
+                    // 바깥 요소의 닫는 태그는 안쪽 요소도 닫는다. 열린 적 없는 닫는 태그는 버린다.
+                    _ if self.open.iter().any(|open| open.eq_ignore_ascii_case(name)) => {
+                        self.pos = start;
+                        return Ok(nodes);
+                    }
+                    _ => {}
+                }
+            } else {
+                if self.html
+                    && let Some(open) = close
+                {
+                    let name = rest[1..]
+                        .split(|c: char| c.is_whitespace() || matches!(c, '>' | '/'))
+                        .next()
+                        .unwrap_or_default()
+                        .to_ascii_lowercase();
+                    if implies_end(open, &name) {
+                        return Ok(nodes);
+                    }
+                }
+                nodes.push(XmlNode::Element(self.parse_element()?));
+            }
+        }
+    }
+
+    fn same_name(&self, a: &str, b: &str) -> bool {
+        if self.html {
+            a.eq_ignore_ascii_case(b)
+        } else {
+            a == b
+        }
+    }
+
+    fn parse_element(&mut self) -> Result<XmlElement, BraillifyError> {
+        self.pos += 1;
+        let name = self.read_name()?;
+        let name = if self.html {
+            name.to_ascii_lowercase()
+        } else {
+            name.to_string()
+        };
+        let mut attributes = vec![];
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(XmlElement {
+                    name,
+                    attributes,
+                    children: vec![],
+                });
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                let children = if self.html && VOID_ELEMENTS.contains(&name.as_str()) {
+                    vec![]
+                } else if self.html && RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                    let rest = self.rest();
+                    let close = format!("</{name}");
+                    let len = rest.to_ascii_lowercase().find(&close).unwrap_or(rest.len());
+                    self.pos += len;
+                    self.skip_past(">").ok();
+                    vec![XmlNode::Text(rest[..len].to_string())]
+                } else {
+                    self.open.push(name.clone());
+                    let children = self.parse_nodes(Some(&name));
+                    self.open.pop();
+                    children?
+                };
+                return Ok(XmlElement {
+                    name,
+                    attributes,
+                    children,
+                });
+            }
+            if rest.is_empty() {
+                return Err(self.error(format!("unterminated tag '<{}'", name)));
+            }
+            let key = self.read_name()?;
+            self.skip_whitespace();
+            let value = if self.rest().starts_with('=') {
+                self.pos += 1;
+                self.skip_whitespace();
+                let rest = self.rest();
+                match rest.chars().next().filter(|c| *c == '"' || *c == '\'') {
+                    Some(quote) => {
+                        let Some(end) = rest[1..].find(quote) else {
+                            return Err(self.error("unterminated attribute value"));
+                        };
+                        self.pos += end + 2;
+                        decode_entities(&rest[1..end + 1])
+                    }
+                    None if self.html => {
+                        let len = rest
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(rest.len());
+                        self.pos += len;
+                        decode_entities(&rest[..len])
+                    }
+                    None => return Err(self.error("expected quoted attribute value")),
+                }
+            } else {
+                String::new()
+            };
+            let key = if self.html {
+                key.to_ascii_lowercase()
+            } else {
+                key.to_string()
+            };
+            attributes.push((key, value));
+        }
+    }
+}
+
+/// XML 문서나 조각을 읽는다. 최상위 노드가 여럿이어도 된다.
+pub fn parse_xml(input: &str) -> Result<Vec<XmlNode>, BraillifyError> {
+    Parser {
+        input,
+        pos: 0,
+        html: false,
+        open: vec![],
+    }
+    .parse_nodes(None)
+}
+
+/// HTML 문서를 읽는다. 요소와 속성 이름은 소문자로 바꾸고, 생략된 닫는 태그를 채워 넣는다.
+pub fn parse_html(input: &str) -> Result<Vec<XmlNode>, BraillifyError> {
+    Parser {
+        input,
+        pos: 0,
+        html: true,
+        open: vec![],
+    }
+    .parse_nodes(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_xml() {
+        let nodes = parse_xml(
+            "<?xml version=\"1.0\"?><!-- c --><m:math xmlns:m='x'><mi>x</mi><mo>&lt;</mo><mspace/></m:math>",
+        )
+        .unwrap();
+        assert_eq!(nodes.len(), 1);
+        let XmlNode::Element(math) = &nodes[0] else {
+            panic!("expected element");
+        };
+        assert_eq!(math.local_name(), "math");
+        assert_eq!(math.attribute("xmlns:m"), Some("x"));
+        assert_eq!(math.elements().count(), 3);
+        assert_eq!(math.text(), "x<");
+        assert_eq!(
+            math.to_xml(),
+            "<m:math xmlns:m=\"x\"><mi>x</mi><mo>&lt;</mo><mspace></mspace></m:math>"
+        );
+    }
+
+    #[test]
+    pub fn test_parse_xml_error() {
+        assert!(parse_xml("<a><b></a>").is_err());
+        assert!(parse_xml("<a>").is_err());
+        assert!(parse_xml("<a b=c></a>").is_err());
+    }
+
+    #[test]
+    pub fn test_parse_html() {
+        let nodes = parse_html(
+            "<!DOCTYPE html><P class=a>하나<p>둘<br>셋<ul><li>가<li>나</ul><img alt=그림><script>a<b</script></div>",
+        )
+        .unwrap();
+        let names = nodes
+            .iter()
+            .map(|node| match node {
+                XmlNode::Element(element) => element.name.as_str(),
+                XmlNode::Text(_) => "#text",
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["p", "p", "ul", "img", "script"]);
+        let XmlNode::Element(p) = &nodes[1] else {
+            panic!("expected element");
+        };
+        assert_eq!(p.text(), "둘셋");
+        let XmlNode::Element(ul) = &nodes[2] else {
+            panic!("expected element");
+        };
+        assert_eq!(
+            ul.elements().map(XmlElement::text).collect::<Vec<_>>(),
+            vec!["가", "나"]
+        );
+        let XmlNode::Element(img) = &nodes[3] else {
+            panic!("expected element");
+        };
+        assert_eq!(img.attribute("alt"), Some("그림"));
+        let XmlNode::Element(script) = &nodes[4] else {
+            panic!("expected element");
+        };
+        assert_eq!(script.text(), "a<b");
+    }
+
+    #[test]
+    pub fn test_decode_entities() {
+        assert_eq!(decode_entities("&#44032;&#xAC01;&amp;"), "가각&");
+        assert_eq!(decode_entities("a & b"), "a & b");
+        assert_eq!(decode_entities("&unknown;"), "&unknown;");
+    }
+}